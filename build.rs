@@ -1,9 +1,96 @@
-// use std::env;
-use std::path::Path;
+use std::{env, fmt::Write, fs, path::Path};
 
 fn main() {
     let dir = "/opt/homebrew/lib";
     if Path::new(dir).exists() {
         println!("cargo:rustc-link-search=native={}", dir);
     }
-}
\ No newline at end of file
+
+    write_solution_registry();
+}
+
+/// Days whose binaries set `required-features` in `Cargo.toml`, with the feature they need.
+const REQUIRED_FEATURES: &[(u8, &str)] = &[(13, "cbc")];
+
+/// Generate the solution registry of the `serve` feature from the day modules in `src/bin`.
+fn write_solution_registry() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    if env::var_os("CARGO_FEATURE_SERVE").is_none() {
+        return;
+    }
+
+    let bin_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/bin");
+    let mut days: Vec<u8> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let stem = name.strip_suffix(".rs")?;
+            // skip scratch modules like `_09.rs`.
+            (stem.len() == 2).then(|| stem.parse().ok()).flatten()
+        })
+        .filter(|day| {
+            REQUIRED_FEATURES.iter().all(|&(d, feature)| {
+                d != *day
+                    || env::var_os(format!("CARGO_FEATURE_{}", feature.to_uppercase())).is_some()
+            })
+        })
+        .collect();
+    days.sort_unstable();
+
+    let out_dir = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let mut out = String::new();
+    for day in &days {
+        let source = fs::read_to_string(bin_dir.join(format!("{day:02}.rs"))).unwrap();
+        let path = out_dir.join(format!("{day:02}.rs"));
+        fs::write(&path, library_module(&source, *day)).unwrap();
+        // the binaries are linted on their own, their style lints should not fail the library.
+        writeln!(
+            out,
+            "#[allow(clippy::all)]\n#[path = {:?}]\nmod day{day:02};",
+            path.display().to_string()
+        )
+        .unwrap();
+    }
+    writeln!(out, "\npub static SOLUTIONS: &[Solution] = &[").unwrap();
+    for day in &days {
+        writeln!(out, "    register!(day{day:02}, {day}),").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), out).unwrap();
+}
+
+/// The source of a day module without the parts that only belong in its binary: the
+/// `solution!` invocation defines `main` and the global allocator, and the tests already run
+/// with the binary.
+fn library_module(source: &str, day: u8) -> String {
+    let mut out = String::new();
+    let mut lines = source.lines();
+
+    while let Some(line) = lines.next() {
+        if line.trim_start().starts_with("advent_of_code::solution!(") {
+            writeln!(
+                out,
+                "const DAY: advent_of_code::template::Day = advent_of_code::day!({day});"
+            )
+            .unwrap();
+        } else if line.trim() == "#[cfg(test)]" {
+            // skip the item that follows, up to its closing brace.
+            let mut depth = 0;
+            for line in lines.by_ref() {
+                depth += line.matches('{').count() as i32 - line.matches('}').count() as i32;
+                if depth <= 0 && (line.contains('}') || line.trim_end().ends_with(';')) {
+                    break;
+                }
+            }
+        } else {
+            writeln!(out, "{line}").unwrap();
+        }
+    }
+
+    out
+}
@@ -1,146 +1,38 @@
 advent_of_code::solution!(3);
 
-use bitvec::prelude::*;
-use std::collections::VecDeque;
-
-// struct BitVec128 {
-//     bits: u128,
-// }
-
-// impl BitVec128 {
-//     fn new() -> Self {
-//         Self { bits: 0 }
-//     }
-
-//     fn to_pos(index: i32) -> Result<u32, String> {
-//         if index < -64 || index > 63 {
-//             return Err(format!("Index {index} out of bounds (-64 to 63)"));
-//         }
-//         Ok((index + 64) as u32)
-//     }
-
-//     fn set(&mut self, index: i32) -> Result<(), String> {
-//         let i = Self::to_pos(index)?;
-//         self.bits |= 1u128 << i;
-//         Ok(())
-//     }
-
-// fn unset(&mut self, index: i32) -> Result<(), String> {
-//     let i = Self::to_pos(index)?;
-//     self.bits &= !(1u128 << i);
-//     Ok(())
-// }
-
-// fn toggle(&mut self, index: i32) -> Result<(), String> {
-//     let i = Self::to_pos(index)?;
-//     self.bits ^= 1u128 << i;
-//     Ok(())
-// }
-
-// fn is_set(&self, index: i32) -> Result<bool, String> {
-//     let i = Self::to_pos(index)?;
-//     Ok((self.bits & (1u128 << i)) != 0)
-// }
+use advent_of_code::grid::SparseGrid;
+
+fn step((r, c): (i64, i64), dir: u8) -> (i64, i64) {
+    match dir {
+        b'^' => (r - 1, c),
+        b'v' => (r + 1, c),
+        b'>' => (r, c + 1),
+        b'<' => (r, c - 1),
+        _ => panic!("invalid direction {}", dir as char),
+    }
+}
 
-//     fn count(&self) -> u32 {
-//         self.bits.count_ones()
-//     }
-// }
+/// Returns the number of houses visited when `santas` take turns following the directions.
+fn deliver(input: &str, santas: usize) -> u64 {
+    let mut houses = SparseGrid::new();
+    let mut positions = vec![(0, 0); santas];
+    houses.insert((0, 0), santas);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let (mut x, mut y, mut z): (u32, u32, bool) = (0, 128, false);
-    let mut d = VecDeque::new();
-    let mut bv = bitvec![0; 256];
-    bv.set(y as usize, true);
-    d.push_back(bv);
-    for i in input.bytes() {
-        // eprintln!("{}, {x}, {y}", i as char);
-        match i {
-            b'>' => x += 1,
-            b'^' => y += 1,
-            b'<' => {
-                if x == 0 {
-                    z = true
-                } else {
-                    x -= 1
-                }
-            }
-            b'v' => y -= 1,
-            _ => panic!(),
-        }
-        step(x, y, z, &mut d);
-        z = false;
+    for (i, dir) in input.trim_end().bytes().enumerate() {
+        let pos = &mut positions[i % santas];
+        *pos = step(*pos, dir);
+        *houses.entry_or_insert_with(*pos, || 0) += 1;
     }
-    let result = d.iter().map(|bv| bv.count_ones() as u64).sum();
-    Some(result)
+
+    houses.len() as u64
 }
 
-fn step(x: u32, y: u32, z: bool, d: &mut VecDeque<BitVec>) {
-    if x >= d.len().try_into().unwrap() {
-        let mut bv = bitvec![0; 256];
-        bv.set(y as usize, true);
-        d.push_back(bv);
-    } else if z {
-        let mut bv = bitvec![0; 256];
-        bv.set(y as usize, true);
-        d.push_front(bv);
-    } else {
-        d[x.try_into().unwrap()].set(y as usize, true);
-    }
+pub fn part_one(input: &str) -> Option<u64> {
+    Some(deliver(input, 1))
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    // eprintln!("-----");
-    let (mut x, mut y, mut z): (u32, u32, bool) = (0, 128, false);
-    let (mut a, mut b, mut c): (u32, u32, bool) = (0, 128, false);
-    let mut d = VecDeque::new();
-    let mut bv = bitvec![0; 256];
-    bv.set(y as usize, true);
-    d.push_back(bv);
-    let mut even = true;
-    for i in input.bytes() {
-        if even {
-            // eprintln!("{}, {x}, {y}", i as char);
-            match i {
-                b'>' => x += 1,
-                b'^' => y += 1,
-                b'<' => {
-                    if x == 0 {
-                        z = true;
-                        a += 1
-                    } else {
-                        x -= 1
-                    }
-                }
-                b'v' => y -= 1,
-                _ => panic!(),
-            }
-            step(x, y, z, &mut d);
-            z = false;
-        } else {
-            // eprintln!("{}, {a}, {b}", i as char);
-            match i {
-                b'>' => a += 1,
-                b'^' => b += 1,
-                b'<' => {
-                    if a == 0 {
-                        c = true;
-                        x += 1;
-                    } else {
-                        a -= 1
-                    }
-                }
-                b'v' => b -= 1,
-                _ => panic!(),
-            }
-            step(a, b, c, &mut d);
-            c = false;
-        }
-
-        even = !even;
-    }
-    let result = d.iter().map(|bv| bv.count_ones() as u64).sum();
-    Some(result)
+    Some(deliver(input, 2))
 }
 
 #[cfg(test)]
@@ -1,111 +1,71 @@
-advent_of_code::solution!(6);
-// use std::simd::{Simd};
-// cargo rustc --release -- --emit=asm -C target-cpu=native
+use advent_of_code::{
+    rect::{Rect, Regions},
+    scan,
+    scan::{ScanError, ScanErrorKind},
+};
 
-fn parse_coords(s: &str) -> Option<((u32, u32), (u32, u32))> {
-    let (a, b) = s.split_once(" through ")?;
-    let (x1, y1) = a.split_once(',')?;
-    let (x2, y2) = b.split_once(',')?;
+advent_of_code::solution!(6);
 
-    Some((
-        (x1.parse().ok()?, y1.parse().ok()?),
-        (x2.parse().ok()?, y2.parse().ok()?),
-    ))
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    On,
+    Off,
+    Toggle,
 }
 
-
-fn toggle_slice(slice: &mut [u8]) {
-    for x in slice {
-        *x ^= 1;
-    }
+/// Parses `turn on 0,0 through 999,999` and the like.
+fn parse_line(line: &str) -> Result<(Action, Rect), ScanError> {
+    let (action, rest) = if let Some(rest) = line.strip_prefix("turn on ") {
+        (Action::On, rest)
+    } else if let Some(rest) = line.strip_prefix("turn off ") {
+        (Action::Off, rest)
+    } else if let Some(rest) = line.strip_prefix("toggle ") {
+        (Action::Toggle, rest)
+    } else {
+        return Err(ScanError::new(
+            1,
+            ScanErrorKind::ExpectedOneOf(&["turn on", "turn off", "toggle"]),
+        ));
+    };
+    // columns are counted from the start of the line, not from after the action.
+    let offset = line[..line.len() - rest.len()].chars().count();
+    let (x0, y0, x1, y1) =
+        scan!(rest, "{x0},{y0} through {x1},{y1}").map_err(|e| e.offset(offset))?;
+    Ok((action, Rect::inclusive(x0, y0, x1, y1)))
 }
 
-fn set_slice(slice: &mut [u8], value: u8) {
-    for x in slice {
-        *x = value;
-    }
+fn parse(input: &str) -> Vec<(Action, Rect)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).unwrap_or_else(|e| panic!("{}", e.at_line(i))))
+        .collect()
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let mut grid = [[0u8; 1000]; 1000];
-    for line in input.lines() {
-        if let Some(line) = line.strip_prefix("turn on ") {
-            let ((xl, yl), (xh, yh)) = parse_coords(line).unwrap();
-                for y in yl..=yh {
-                    let slice = &mut grid[y as usize][xl as usize..=(xh as usize)];
-                    set_slice(slice, 1);
-                }
-        } else if let Some(line) = line.strip_prefix("turn off ") {
-            let ((xl, yl), (xh, yh)) = parse_coords(line).unwrap();
-                for y in yl..=yh {
-                    let slice = &mut grid[y as usize][xl as usize..=(xh as usize)];
-                    set_slice(slice, 0);
-                }
-
-        } else if let Some(line) = line.strip_prefix("toggle ") {
-            let ((xl, yl), (xh, yh)) = parse_coords(line).unwrap();
-                for y in yl..=yh {
-                    let slice = &mut grid[y as usize][xl as usize..=(xh as usize)];
-                    toggle_slice(slice);
-                }
-        } else {
-            panic!();
+    let instructions = parse(input);
+    let mut lights = Regions::new(instructions.iter().map(|(_, r)| r), false);
+    for (action, rect) in &instructions {
+        match action {
+            Action::On => lights.update(rect, |on| *on = true),
+            Action::Off => lights.update(rect, |on| *on = false),
+            Action::Toggle => lights.update(rect, |on| *on = !*on),
         }
     }
-
-    let count = grid.iter().flatten().map(|&x| x as u64).sum();
-    Some(count)
-}
-
-fn on_slice(slice: &mut [u16]) {
-    for x in slice {
-        *x += 1;
-    }
+    Some(lights.sum(|&on| u64::from(on)))
 }
 
-fn off_slice(slice: &mut [u16]) {
-    for x in slice {
-
-        *x = std::cmp::max(*x, 1) - 1;
-    }
-}
-
-fn toggle_slice2(slice: &mut [u16]) {
-    for x in slice {
-        *x += 2;
-    }
-}
-
-
 pub fn part_two(input: &str) -> Option<u64> {
-    let mut grid = [[0u16; 1000]; 1000];
-    for line in input.lines() {
-        if let Some(line) = line.strip_prefix("turn on ") {
-            let ((xl, yl), (xh, yh)) = parse_coords(line).unwrap();
-                for y in yl..=yh {
-                    let slice = &mut grid[y as usize][xl as usize..=(xh as usize)];
-                    on_slice(slice);
-                }
-        } else if let Some(line) = line.strip_prefix("turn off ") {
-            let ((xl, yl), (xh, yh)) = parse_coords(line).unwrap();
-                for y in yl..=yh {
-                    let slice = &mut grid[y as usize][xl as usize..=(xh as usize)];
-                    off_slice(slice);
-                }
-
-        } else if let Some(line) = line.strip_prefix("toggle ") {
-            let ((xl, yl), (xh, yh)) = parse_coords(line).unwrap();
-                for y in yl..=yh {
-                    let slice = &mut grid[y as usize][xl as usize..=(xh as usize)];
-                    toggle_slice2(slice);
-                }
-        } else {
-            panic!();
+    let instructions = parse(input);
+    let mut brightness = Regions::new(instructions.iter().map(|(_, r)| r), 0u64);
+    for (action, rect) in &instructions {
+        match action {
+            Action::On => brightness.update(rect, |b| *b += 1),
+            Action::Off => brightness.update(rect, |b| *b = b.saturating_sub(1)),
+            Action::Toggle => brightness.update(rect, |b| *b += 2),
         }
     }
-
-    let count = grid.iter().flatten().map(|&x| x as u64).sum();
-    Some(count)
+    Some(brightness.sum(|&b| b))
 }
 
 #[cfg(test)]
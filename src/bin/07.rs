@@ -1,30 +1,19 @@
 #![allow(unused)]
 use std::{cell::RefCell, collections::HashMap};
 
+use advent_of_code::{
+    scan,
+    scan::{ScanError, ScanErrorKind},
+};
+
 // use itertools::Itertools;
 
 advent_of_code::solution!(7);
 
-macro_rules! split3 {
-    ($s:expr) => {{ // double scope to contain iter
-        let mut iter = $s.split_whitespace();
-        let l = iter.next().expect("missing");
-        if let Some(m) = iter.next() {
-            if let Some(r) = iter.next() {
-                (l, m, r)
-            } else {
-                (m, l, "")
-            }
-        } else {
-            (l, "", "")
-        }
-    }};
-}
-
 #[derive(Debug, Clone)]
 enum Source {
     Label(String),
-    Value(u64)
+    Value(u64),
 }
 
 impl From<&str> for Source {
@@ -46,19 +35,24 @@ impl Source {
                     borrowed.get(s).cloned()? // !important
                 };
                 let (result, computed) = op.eval(map)?;
-                if ! computed {
-                    if let Some(v) = map.borrow_mut().get_mut(s) { // update
+                if !computed {
+                    if let Some(v) = map.borrow_mut().get_mut(s) {
+                        // update
                         *v = Op::_VAL(result);
                         // *v = Op::SET(Source::Value(result)); // 4x speedup replacing this!
                     }
                 }
                 Some(result)
-            },
+            }
             Self::Value(v) => Some(v.clone()),
         }
     }
 
-    fn eval_cache(&self, map: &HashMap<String, Op>, cache: &mut HashMap<String, u64>) -> Option<u64> {
+    fn eval_cache(
+        &self,
+        map: &HashMap<String, Op>,
+        cache: &mut HashMap<String, u64>,
+    ) -> Option<u64> {
         match self {
             Self::Label(s) => {
                 if let Some(cached_value) = cache.get(s) {
@@ -68,7 +62,7 @@ impl Source {
                 let result = op.eval_cache(map, cache)?;
                 cache.insert(s.clone(), result);
                 Some(result)
-            },
+            }
             Self::Value(v) => Some(*v),
         }
     }
@@ -82,78 +76,86 @@ enum Op {
     OR(Source, Source),
     RSHIFT(Source, Source),
     SET(Source),
-    _VAL(u64)
+    _VAL(u64),
 }
 
 impl Op {
     fn eval(&self, map: &RefCell<HashMap<String, Op>>) -> Option<(u64, bool)> {
         let mut computed = false;
-        let result = 
-            match self {
-                Self::AND(l, r) => l.eval(map)? & r.eval(map)?,
-                Self::OR(l, r) => l.eval(map)? | r.eval(map)?,
-                Self::LSHIFT(l, r) => l.eval(map)? << r.eval(map)?,
-                Self::RSHIFT(l, r) => l.eval(map)? >> r.eval(map)?,
-                Self::NOT(l) => ! l.eval(map)?,
-                Self::SET(l) => l.eval(map)?,
-                Self::_VAL(l) => {
-                    computed = true;
-                    *l
-                },
-            };
+        let result = match self {
+            Self::AND(l, r) => l.eval(map)? & r.eval(map)?,
+            Self::OR(l, r) => l.eval(map)? | r.eval(map)?,
+            Self::LSHIFT(l, r) => l.eval(map)? << r.eval(map)?,
+            Self::RSHIFT(l, r) => l.eval(map)? >> r.eval(map)?,
+            Self::NOT(l) => !l.eval(map)?,
+            Self::SET(l) => l.eval(map)?,
+            Self::_VAL(l) => {
+                computed = true;
+                *l
+            }
+        };
         Some((result, computed))
     }
 
-    fn eval_cache(&self, map: &HashMap<String, Op>, cache: &mut HashMap<String, u64>) -> Option<u64> {
-        let result =
-            match self {
-                Self::AND(l, r) => l.eval_cache(map, cache)? & r.eval_cache(map, cache)?,
-                Self::OR(l, r) => l.eval_cache(map, cache)? | r.eval_cache(map, cache)?,
-                Self::LSHIFT(l, r) => l.eval_cache(map, cache)? << r.eval_cache(map, cache)?,
-                Self::RSHIFT(l, r) => l.eval_cache(map, cache)? >> r.eval_cache(map, cache)?,
-                Self::NOT(l) => !l.eval_cache(map, cache)?,
-                Self::SET(l) => l.eval_cache(map, cache)?,
-                Self::_VAL(l) => {
-                    *l
-                },
-            };
+    fn eval_cache(
+        &self,
+        map: &HashMap<String, Op>,
+        cache: &mut HashMap<String, u64>,
+    ) -> Option<u64> {
+        let result = match self {
+            Self::AND(l, r) => l.eval_cache(map, cache)? & r.eval_cache(map, cache)?,
+            Self::OR(l, r) => l.eval_cache(map, cache)? | r.eval_cache(map, cache)?,
+            Self::LSHIFT(l, r) => l.eval_cache(map, cache)? << r.eval_cache(map, cache)?,
+            Self::RSHIFT(l, r) => l.eval_cache(map, cache)? >> r.eval_cache(map, cache)?,
+            Self::NOT(l) => !l.eval_cache(map, cache)?,
+            Self::SET(l) => l.eval_cache(map, cache)?,
+            Self::_VAL(l) => *l,
+        };
         Some(result)
     }
 }
 
-fn parse_line(line: &str)  -> Option<(String, Op)> {
-    let (l, target) = line.split_once(" -> ")?;
-    let (l, _op, r) = split3!(l);
-    let op = match _op {
-        "AND"    => Op::AND(Source::from(l), Source::from(r)),
-        "OR"     => Op::OR(Source::from(l), Source::from(r)),
-        "LSHIFT" => Op::LSHIFT(Source::from(l), Source::from(r)),
-        "RSHIFT" => Op::RSHIFT(Source::from(l), Source::from(r)),
-        "NOT"    => Op::NOT(Source::from(l)),
-        ""       => {
-            if let Ok(u) = l.parse::<u64>() {
-                Op::_VAL(u)
-            } else {
-                Op::SET(Source::from(l))
+fn parse_line(line: &str) -> Result<(String, Op), ScanError> {
+    let (expr, target): (&str, &str) = scan!(line, "{expr} -> {target}")?;
+    let op = if let Ok::<(&str, &str, &str), _>((l, op, r)) = scan!(expr, "{l} {op} {r}") {
+        // `expr` starts the line, so its columns are those of the line.
+        let op_column = l.chars().count() + 2;
+        let (l, r) = (Source::from(l), Source::from(r));
+        match op {
+            "AND" => Op::AND(l, r),
+            "OR" => Op::OR(l, r),
+            "LSHIFT" => Op::LSHIFT(l, r),
+            "RSHIFT" => Op::RSHIFT(l, r),
+            _ => {
+                return Err(ScanError::new(
+                    op_column,
+                    ScanErrorKind::ExpectedOneOf(&["AND", "OR", "LSHIFT", "RSHIFT"]),
+                ));
             }
         }
-        _        => {
-            eprintln!("Invalid line {line}");
-            return None
-        },
+    } else if let Ok::<&str, _>(l) = scan!(expr, "NOT {l}") {
+        Op::NOT(Source::from(l))
+    } else if let Ok(u) = expr.parse::<u64>() {
+        Op::_VAL(u)
+    } else {
+        Op::SET(Source::from(expr))
     };
 
-    Some((target.to_string(), op))
+    Ok((target.to_string(), op))
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let mut d: HashMap<String, Op> = HashMap::new();
+fn parse(input: &str) -> HashMap<String, Op> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| parse_line(line).unwrap_or_else(|e| panic!("{}", e.at_line(i))))
+        .collect()
+}
 
-    for line in input.lines() {
-        let (k, v) = parse_line(line)?;
-        // d.entry(k).or_default().push(v); // mutate (not needed because each wire defined once)
-        d.insert(k, v);
-    }
+pub fn part_one(input: &str) -> Option<u64> {
+    // each wire is defined once, so collecting into a map loses nothing.
+    let d = parse(input);
 
     // eprintln!("{:#?}", d);
 
@@ -163,12 +165,7 @@ pub fn part_one(input: &str) -> Option<u64> {
 pub fn part_two(input: &str) -> Option<u64> {
     let val_a = part_one(input)?;
 
-    let mut d: HashMap<String, Op> = HashMap::new();
-    for line in input.lines() {
-        let (k, v) = parse_line(line)?;
-        d.insert(k, v);
-    }
-
+    let mut d = parse(input);
     d.insert("b".to_string(), Op::_VAL(val_a));
 
     let mut cache = HashMap::new();
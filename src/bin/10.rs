@@ -1,5 +1,3 @@
-
-
 advent_of_code::solution!(10);
 
 // also, itertools.group_by
@@ -25,9 +23,8 @@ pub fn lookandsay(digits: Vec<u8>) -> Vec<u8> {
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    dbg!(input);
-
-    let mut digits: Vec<u8> = input.trim()
+    let mut digits: Vec<u8> = input
+        .trim()
         .chars()
         .map(|c| c.to_digit(10).map(|d| d as u8))
         .collect::<Option<Vec<u8>>>()?;
@@ -40,7 +37,8 @@ pub fn part_one(input: &str) -> Option<u64> {
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let mut digits: Vec<u8> = input.trim()
+    let mut digits: Vec<u8> = input
+        .trim()
         .chars()
         .map(|c| c.to_digit(10).map(|d| d as u8))
         .collect::<Option<Vec<u8>>>()?;
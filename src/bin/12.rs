@@ -1,9 +1,6 @@
-
-
 advent_of_code::solution!(12);
 
-
-use serde::de::{self, Deserialize, Deserializer, Visitor, SeqAccess, MapAccess};
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
 use std::fmt;
 
 struct SumNumbers(i64);
@@ -104,7 +101,7 @@ impl<'de> Deserialize<'de> for SumNumbersNoRed {
                 E: de::Error,
             {
                 if v == "red" {
-                    return Err(de::Error::custom("red"))
+                    return Err(de::Error::custom("red"));
                 }
                 Ok(0)
             }
@@ -122,7 +119,7 @@ impl<'de> Deserialize<'de> for SumNumbersNoRed {
             {
                 Ok(v as i64)
             }
-            
+
             fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
             where
                 A: SeqAccess<'de>,
@@ -195,7 +192,6 @@ impl<'de> Deserialize<'de> for SumNumbersNoRed {
     }
 }
 
-
 pub fn part_one(input: &str) -> Option<u64> {
     let result: SumNumbers = serde_json::from_str(input).unwrap();
     Some(result.0 as u64)
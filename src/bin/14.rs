@@ -1,40 +1,36 @@
-
-
 use std::collections::HashMap;
 
+use advent_of_code::scan;
 use num_integer::Integer;
 
 advent_of_code::solution!(14);
 
-pub struct ContestantStats { v: u64, l: u64, r: u64 }
-type Contestants = HashMap<String,ContestantStats>;
+pub struct ContestantStats {
+    v: u64,
+    l: u64,
+    r: u64,
+}
+type Contestants = HashMap<String, ContestantStats>;
 
-pub fn parse(input: &str) -> Option<Contestants> {
+pub fn parse(input: &str) -> Contestants {
     let mut contestants = HashMap::new();
 
-    for line in input.lines() {
-        let line = line.trim_end_matches('.');
-
-        let (name, rest) = line.split_once(" can fly ")?;
-
-        let (speed_str, rest) = rest.split_once(" km/s for ")?;
-        let v = speed_str.parse::<u64>().ok()?;
-
-        let (duration_str, rest) = rest.split_once(" seconds, but then must rest for ")?;
-        let l = duration_str.parse::<u64>().ok()?;
-
-        let r = rest.split_once(" seconds").map(|(r, _)| r)?.parse::<u64>().ok()?;
-
-        contestants.insert(name.to_string(), ContestantStats {v, l, r});
+    let reindeer: Vec<(&str, u64, u64, u64)> = scan!(
+        lines input,
+        "{name} can fly {v} km/s for {l} seconds, but then must rest for {r} seconds."
+    )
+    .unwrap_or_else(|e| panic!("{e}"));
+    for (name, v, l, r) in reindeer {
+        contestants.insert(name.to_string(), ContestantStats { v, l, r });
     }
 
-    Some(contestants)
+    contestants
 }
 
 pub fn compute(duration: u64, stats: &ContestantStats) -> u64 {
-    let ContestantStats {v, l, r} = stats;
-    let (q, rem) = duration.div_rem(&(l+r));
-    q * l * v + l.min(&rem)*v
+    let ContestantStats { v, l, r } = stats;
+    let (q, rem) = duration.div_rem(&(l + r));
+    q * l * v + l.min(&rem) * v
 }
 
 pub fn ahead(ds: &[u64], stats: &ContestantStats) -> ([u64; 2503], bool) {
@@ -60,17 +56,12 @@ pub fn ahead(ds: &[u64], stats: &ContestantStats) -> ([u64; 2503], bool) {
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let contestants = parse(input)?;
-    contestants
-        .values()
-        .map(|stats| {
-            compute(2503, stats)
-        })
-        .max()
+    let contestants = parse(input);
+    contestants.values().map(|stats| compute(2503, stats)).max()
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let contestants = parse(input)?;
+    let contestants = parse(input);
     let n = contestants.len();
     let mut ds = vec![0; n];
     let mut ss = vec![0u64; n];
@@ -81,13 +72,13 @@ pub fn part_two(input: &str) -> Option<u64> {
             }
         }
         let max = ds.iter().max().unwrap();
-        for i in ds.iter()
+        for i in ds
+            .iter()
             .enumerate()
-            .filter_map(|(i, &val)|
-                if val == *max { Some(i) } else { None }
-            ) {
-                ss[i] += 1
-            }
+            .filter_map(|(i, &val)| if val == *max { Some(i) } else { None })
+        {
+            ss[i] += 1
+        }
     }
     ss.iter().max().copied()
 }
@@ -1,5 +1,3 @@
-
-
 advent_of_code::solution!(16);
 
 use itertools::Itertools;
@@ -19,12 +17,18 @@ static SUE_CONSTRAINT: phf::Map<&'static str, u8> = phf_map! {
 };
 
 pub fn parse(line: &str) -> impl Iterator<Item = (&str, u8)> {
-    line.split_ascii_whitespace().skip(2).tuples().filter_map(|(k, v)| {
-        Some((k.trim_end_matches(':'), v.trim_end_matches(',').parse::<u8>().ok()?))
-    })
+    line.split_ascii_whitespace()
+        .skip(2)
+        .tuples()
+        .filter_map(|(k, v)| {
+            Some((
+                k.trim_end_matches(':'),
+                v.trim_end_matches(',').parse::<u8>().ok()?,
+            ))
+        })
 }
 
-pub fn check<'a> (mut iter: impl Iterator<Item = (&'a str, u8)>) -> bool {
+pub fn check<'a>(mut iter: impl Iterator<Item = (&'a str, u8)>) -> bool {
     iter.all(|(k, v)| *SUE_CONSTRAINT.get(k).unwrap() == v)
 }
 
@@ -32,30 +36,26 @@ pub fn part_one(input: &str) -> Option<u64> {
     let mut count = 1;
     for line in input.lines() {
         if check(parse(line)) {
-            return Some(count)
+            return Some(count);
         }
         count = count + 1;
     }
-    None        
+    None
 }
 
-pub fn check2<'a> (mut iter: impl Iterator<Item = (&'a str, u8)>) -> bool {
-    iter.all(|(k, v)| {
-            match k {
-                "cats" | "trees" => *SUE_CONSTRAINT.get(k).unwrap() < v,
-                "pomeranians" | "goldfish" => *SUE_CONSTRAINT.get(k).unwrap() > v,
-                _ => *SUE_CONSTRAINT.get(k).unwrap() == v
-            }
-            
-        }
-    )
+pub fn check2<'a>(mut iter: impl Iterator<Item = (&'a str, u8)>) -> bool {
+    iter.all(|(k, v)| match k {
+        "cats" | "trees" => *SUE_CONSTRAINT.get(k).unwrap() < v,
+        "pomeranians" | "goldfish" => *SUE_CONSTRAINT.get(k).unwrap() > v,
+        _ => *SUE_CONSTRAINT.get(k).unwrap() == v,
+    })
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
     let mut count = 1;
     for line in input.lines() {
         if check2(parse(line)) {
-            return Some(count)
+            return Some(count);
         }
         count = count + 1;
     }
@@ -1,6 +1,6 @@
 #![allow(unused_variables, unused_macros)]
 
-use itertools::iproduct;
+use advent_of_code::grid::Grid;
 
 // todo: trace with tracing
 
@@ -14,52 +14,12 @@ macro_rules! debug_eprintln {
     };
 }
 
-type Grid = Vec<Vec<bool>>;
-type UGrid = Vec<Vec<u64>>;
-
-pub fn parse(input:&str) -> Vec<Vec<bool>> {
-    let mut ret = Vec::new();
-    for l in input.lines() {
-        let row = l.chars().map(|f| f == '#').collect();
-        ret.push(row);
-    }
-    ret
-}
-
-pub fn neighbors(
-    m: usize,
-    n: usize,
-    i: usize,
-    j: usize,
-) -> impl Iterator<Item = (usize, usize)> {
-    match (i, j) {
-        (0, 0) => vec![(0, 1), (1, 0), (1, 1)],
-        (0, y) if y + 1 == n => vec![(0, y - 1), (1, y - 1), (1, y)],
-        (x, 0) if x + 1 == m => vec![(x - 1, 0), (x - 1, 1), (x, 1)],
-        (x, y) if x + 1 == m && y + 1 == n => vec![(x - 1, y - 1), (x - 1, y), (x, y - 1)],
-        (0, y) => vec![(0, y - 1), (0, y + 1), (1, y - 1), (1, y), (1, y + 1)],
-        (x, 0) => vec![(x - 1, 0), (x + 1, 0), (x - 1, 1), (x, 1), (x + 1, 1)],
-        (x, y) if y + 1 == n => vec![(x - 1, y), (x + 1, y), (x - 1, y - 1), (x, y - 1), (x + 1, y - 1)],
-        (x, y) if x + 1 == m => vec![(x, y - 1), (x, y + 1), (x - 1, y - 1), (x - 1, y), (x - 1, y + 1)],
-        (x, y) => vec![
-            (x - 1, y - 1), (x - 1, y), (x - 1, y + 1),
-            (x, y - 1),                 (x, y + 1),
-            (x + 1, y - 1), (x + 1, y), (x + 1, y + 1),
-        ],
-    }
-    .into_iter()
+pub fn parse(input: &str) -> Grid<bool> {
+    Grid::parse(input, |c| c == '#')
 }
 
-pub fn clear(scratch: &mut UGrid) {
-    for row in scratch.iter_mut() {
-        for v in row.iter_mut() {
-            *v = 0;
-        }
-    }
-}
-
-pub fn count(grid: &Grid) -> usize {
-    grid.iter().flatten().filter(|&&b| b).count()
+pub fn count(grid: &Grid<bool>) -> usize {
+    grid.iter().filter(|&&b| b).count()
 }
 
 pub fn transition(gridv: bool, scratchv: u64) -> bool {
@@ -67,31 +27,28 @@ pub fn transition(gridv: bool, scratchv: u64) -> bool {
         (true, 2) | (true, 3) => true,
         (true, _) => false,
         (false, 3) => true,
-        (false , _) => false,
+        (false, _) => false,
     }
 }
 
-pub fn step(grid: &mut Grid, scratch: &mut UGrid) {
-    let m = grid.len();
-    let n = grid[0].len();
-    clear(scratch);
-    for (i, j) in iproduct!(0..m, 0..n) {
-        if grid[i][j] {
-            for (x, y) in neighbors(m, n, i, j) {
-                scratch[x][y] += 1;
+pub fn step(grid: &mut Grid<bool>, scratch: &mut Grid<u64>) {
+    scratch.fill(0);
+    for pos in grid.positions() {
+        if grid[pos] {
+            for n in grid.neighbors8(pos) {
+                scratch[n] += 1;
             }
         }
     }
-    for (i, j) in iproduct!(0..m, 0..n) {
-        grid[i][j] = transition(grid[i][j], scratch[i][j])
+    for (cell, &n) in grid.iter_mut().zip(scratch.iter()) {
+        *cell = transition(*cell, n)
     }
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    let mut grid = parse(&input);
-    let m = grid.len();
-    let n = grid[0].len();
-    let mut scratch = vec![vec![0; n]; m];
+    let mut grid = parse(input);
+    let (m, n) = (grid.rows(), grid.cols());
+    let mut scratch = Grid::new(m, n, 0);
     for _ in 0..100 {
         step(&mut grid, &mut scratch)
     }
@@ -99,16 +56,15 @@ pub fn part_one(input: &str) -> Option<u64> {
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let mut grid = parse(&input);
-    let m = grid.len();
-    let n = grid[0].len();
-    let mut scratch = vec![vec![0; n]; m];
+    let mut grid = parse(input);
+    let (m, n) = (grid.rows(), grid.cols());
+    let mut scratch = Grid::new(m, n, 0);
     for _ in 0..100 {
         step(&mut grid, &mut scratch);
-        grid[0][0] = true;
-        grid[0][n-1] = true;
-        grid[m-1][0] = true;
-        grid[m-1][n-1] = true;
+        grid[(0, 0)] = true;
+        grid[(0, n - 1)] = true;
+        grid[(m - 1, 0)] = true;
+        grid[(m - 1, n - 1)] = true;
     }
     Some(count(&grid) as u64)
 }
@@ -128,4 +84,4 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, None);
     }
-}
\ No newline at end of file
+}
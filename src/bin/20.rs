@@ -1,92 +1,25 @@
-#![allow(unused_variables, unused_macros)]
+use advent_of_code::number::{sigma_sieve, sigma_sieve_limited};
 
 advent_of_code::solution!(20);
 
-macro_rules! debug_eprintln {
-    ($($arg:tt)*) => {
-        if cfg!(debug_assertions) {
-            eprintln!($($arg)*);
-        }
-    };
+/// The first house whose presents reach `target`, where house `n` gets `sums[n]` presents.
+fn first_house(sums: &[u64], target: u64) -> Option<u64> {
+    sums.iter().position(|&n| n >= target).map(|i| i as u64)
 }
 
-pub fn part_one_orig(input: &str) -> Option<u64> {
-    let target = input.trim().parse::<usize>().unwrap() / 10;
-
-    let mut arr = vec![1; target];
-
-    let mut curr = 2;
-    let mut upper = target;
-
-    while curr < upper {
-        for i in (curr - 1..upper).step_by(curr) {
-            arr[i] += curr;
-            if arr[i] >= target {
-                upper = i;
-                break;
-            }
-        }
-        curr += 1;
-    }
-
-    Some(upper as u64 + 1)
-}
-
-// apparently faster
 pub fn part_one(input: &str) -> Option<u64> {
-    let target = input.trim().parse::<usize>().unwrap() / 10;
-
-    let mut arr = vec![1; target];
-
-    let mut curr = 2;
-
-    while curr < target {
-        for i in (curr - 1..target).step_by(curr) {
-            arr[i] += curr;
-        }
-        curr += 1;
-    }
-
-    Some(
-        arr.iter()
-            .enumerate()
-            .find_map(|(i, &n)| {
-                if n >= target {
-                    Some(i as u64 + 1)
-                } else {
-                    None
-                }
-            })
-            .unwrap(),
-    )
+    // every elf delivers 10 times its number, so house `n` gets `10 * sigma(n)` presents.
+    let target = input.trim().parse::<u64>().ok()?.div_ceil(10);
+    // `sigma(n) > n`, so house `target` always suffices.
+    let sums = sigma_sieve(target as usize);
+    first_house(&sums[1..], target).map(|i| i + 1)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let target = input.trim().parse::<usize>().unwrap();
-
-    let mut arr = vec![11; target / 11];
-
-    let mut curr = 2;
-
-    while curr < target {
-        for i in (curr - 1..arr.len()).step_by(curr).take(50) {
-            arr[i] += curr * 11;
-        }
-        curr += 1;
-    }
-
-    Some(
-        arr.iter()
-            .enumerate()
-            .find_map(|(i, &n)| {
-                if n >= target {
-                    Some(i as u64 + 1)
-                } else {
-                    None
-                }
-            })
-            .unwrap(),
-    )
+    // every elf delivers 11 times its number to its first 50 houses.
+    let target = input.trim().parse::<u64>().ok()?.div_ceil(11);
+    let sums = sigma_sieve_limited(target as usize, 50);
+    first_house(&sums[1..], target).map(|i| i + 1)
 }
 
 #[cfg(test)]
@@ -102,6 +35,6 @@ mod tests {
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        assert_eq!(result, Some(6));
     }
 }
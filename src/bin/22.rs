@@ -2,6 +2,8 @@
 
 use std::u64;
 
+use advent_of_code::search;
+
 advent_of_code::solution!(22);
 
 macro_rules! debug_eprintln {
@@ -48,6 +50,9 @@ pub struct State {
     shield_t: u16,
     mana_total: u64,
     turn: usize,
+    /// the player loses a health point at the start of each of their turns.
+    hard: bool,
+    won: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -179,70 +184,81 @@ fn dec(t: &mut u16, n: u16) {
     *t = t.saturating_sub(n);
 }
 
-fn dfs<const HARD: bool>(start: State) -> u64 {
-    let mut min = u64::MAX;
-    let mut iterations = 0;
-    // dequeue for
-    let mut states = vec![(Spell::DRAIN, start)];
+impl search::State for State {
+    type Key = (u16, u16, u16, u16, u16, u16, bool);
+    type Cost = u64;
 
-    // Some kind of lower bound of remaining mana needed would greatly improve pruning
-    while let Some((s, mut curr)) = states.pop() {
-        iterations += 1;
-        if HARD {
+    fn successors(&self) -> Vec<(Self, u64)> {
+        let mut curr = self.clone();
+        if curr.hard {
             if curr.health > 1 {
                 dec(&mut curr.health, 1);
             } else {
-                continue;
+                return vec![];
             }
-        };
-        if curr.turn > 0 {
-            debug_eprintln!("{}{s:?}", " ".repeat(curr.turn - 1));
         }
 
-        // debug_eprintln!("---------\n{curr:?} {min}\n---------");
-
-        let spells = curr.spells();
-        for s in spells {
-            let next = curr.clone().process(s.clone());
-
-            match next {
-                Ok(state) if state.mana_total < min => {
-                    // debug_eprintln!("{state:?}");
-                    states.push((s, state));
-                }
-                Err(c) if c != 0 => {
-                    min = min.min(c);
-                    debug_eprintln!(
-                        "{}{s:?}\n{}{c}",
-                        " ".repeat(curr.turn + 1),
-                        " ".repeat(curr.turn + 1)
-                    );
-                }
-                _ => {
-                    // debug_eprintln!("{}pruned", " ".repeat(curr.turn));
-                    continue;
-                }
-            }
-        }
+        curr.spells()
+            .into_iter()
+            .filter_map(|s| match curr.clone().process(s) {
+                Ok(state) => Some(state),
+                // lost
+                Err(0) => None,
+                Err(mana_total) => Some(State {
+                    b_health: 0,
+                    mana_total,
+                    won: true,
+                    ..curr.clone()
+                }),
+            })
+            .map(|state| {
+                let cost = state.mana_total - self.mana_total;
+                (state, cost)
+            })
+            .collect()
+    }
+
+    fn is_goal(&self) -> bool {
+        self.won
+    }
+
+    fn key(&self) -> Self::Key {
+        (
+            self.b_health,
+            self.health,
+            self.mana,
+            self.poison_t,
+            self.recharge_t,
+            self.shield_t,
+            self.won,
+        )
     }
 
-    debug_eprintln!("{}", iterations);
+    /// Poison deals the most damage per mana, so the boss health left after the active poison
+    /// wears off costs at least that rate.
+    fn heuristic(&self) -> u64 {
+        let remaining = self.b_health.saturating_sub(POISON * self.poison_t) as u64;
+        remaining * Spell::POISON.cost() as u64 / (POISON as u64 * 6)
+    }
+}
 
-    min
+fn solve(start: State) -> Option<u64> {
+    let outcome = search::astar(start);
+    debug_eprintln!("{:?}", outcome.stats);
+    outcome.cost()
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
     let start = parse(input);
-    let ret = dfs::<false>(start);
-
-    Some(ret)
+    solve(start)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let start = parse(input);
-    let ret = dfs::<true>(start);
-
-    Some(ret)
+    let start = State {
+        hard: true,
+        ..parse(input)
+    };
+    solve(start)
 }
 
 #[cfg(test)]
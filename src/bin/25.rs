@@ -1,6 +1,7 @@
 #![allow(unused_variables, unused_macros)]
 
-use aoc_lib::parse::parse_first_line;
+use advent_of_code::number::{diagonal_index, mod_pow};
+use advent_of_code::parse::parse_first_line;
 
 advent_of_code::solution!(25);
 
@@ -12,30 +13,14 @@ macro_rules! debug_eprintln {
     };
 }
 
-fn pow(mut base: u64, mut exp: usize, modulo: u64) -> u64 {
-    let mut result = 1;
-    base %= modulo;
-    while exp > 0 {
-        if exp & 1 == 1 {
-            result = result * base % modulo;
-        }
-        base = base * base % modulo;
-        exp >>= 1;
-    }
-    result
-}
-
 static N: u64 = 20151125;
 static B: u64 = 252533;
 static M: u64 = 33554393;
 
-pub fn parse(s: &str) -> usize {
-    let x: [usize; 2] = parse_first_line(s); // row, column
-                                             // 3, 4 -> T_5 + 4
-    let n = x[0] + x[1] - 2;
-    let t_n = (n * (n + 1)) / 2;
-    let ret = t_n + x[1];
-    debug_eprintln!("{x:?}, {ret}");
+pub fn parse(s: &str) -> u64 {
+    let [row, col] = parse_first_line(s);
+    let ret = diagonal_index(row, col);
+    debug_eprintln!("{row}, {col}: {ret}");
 
     ret - 1
 }
@@ -43,7 +28,7 @@ pub fn parse(s: &str) -> usize {
 pub fn part_one(input: &str) -> Option<u64> {
     let iters = parse(input);
 
-    let mut ret = pow(B, iters, M);
+    let mut ret = mod_pow(B, iters, M);
     ret = (ret * N) % M;
 
     Some(ret)
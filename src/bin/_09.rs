@@ -1,7 +1,10 @@
 #![allow(warnings)]
 
 use ndarray::{Array2, ArrayView2};
-use std::{cmp::Ordering, collections::{BinaryHeap, HashMap}};
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+};
 
 advent_of_code::solution!(9);
 
@@ -104,9 +107,9 @@ pub fn part_one(input: &str) -> Option<u64> {
     for line in input.lines() {
         let (l, r) = line.split_once(" = ")?;
         let (s, e) = l.split_once(" to ")?;
-        d.entry(l.to_string()).or_default().insert(e.to_string(), r.parse::<u64>().ok()?);
-        
-         
+        d.entry(l.to_string())
+            .or_default()
+            .insert(e.to_string(), r.parse::<u64>().ok()?);
     }
     todo!()
 }
@@ -0,0 +1,201 @@
+//! Subset sums and equal partitions of small collections of integers.
+//!
+//! Subsets are returned as ascending indices into the input, so equal values are told apart
+//! (e.g. two containers of the same size).
+use std::cmp::Reverse;
+
+/// The number of subsets of `values` that sum to `target`.
+pub fn count_subsets(values: &[u64], target: u64) -> u64 {
+    let target = target as usize;
+    // `counts[s]` is the number of subsets of the values seen so far that sum to `s`.
+    let mut counts = vec![0u64; target + 1];
+    counts[0] = 1;
+
+    for &v in values {
+        let v = v as usize;
+        for s in (v..=target).rev() {
+            counts[s] += counts[s - v];
+        }
+    }
+
+    counts[target]
+}
+
+/// The number of subsets of `values` that sum to `target`, by subset size.
+/// Index `k` of the result counts the subsets with `k` elements.
+pub fn count_subsets_by_size(values: &[u64], target: u64) -> Vec<u64> {
+    let target = target as usize;
+    // `counts[k][s]` is the number of subsets of size `k` that sum to `s`.
+    let mut counts = vec![vec![0u64; target + 1]; values.len() + 1];
+    counts[0][0] = 1;
+
+    for (i, &v) in values.iter().enumerate() {
+        let v = v as usize;
+        for k in (1..=i + 1).rev() {
+            for s in (v..=target).rev() {
+                counts[k][s] += counts[k - 1][s - v];
+            }
+        }
+    }
+
+    counts.iter().map(|row| row[target]).collect()
+}
+
+/// Every subset of `values` with exactly `size` elements that sums to `target`.
+pub fn subsets_of_size(values: &[u64], size: usize, target: u64) -> Vec<Vec<usize>> {
+    fn extend(
+        values: &[u64],
+        order: &[usize],
+        size: usize,
+        target: u64,
+        chosen: &mut Vec<usize>,
+        out: &mut Vec<Vec<usize>>,
+    ) {
+        if size == 0 {
+            if target == 0 {
+                let mut subset = chosen.clone();
+                subset.sort_unstable();
+                out.push(subset);
+            }
+            return;
+        }
+
+        for (j, &i) in order.iter().enumerate() {
+            let v = values[i];
+            // the values are in descending order, so none of the remaining ones can reach the target.
+            if v * (size as u64) < target {
+                break;
+            }
+            if v > target {
+                continue;
+            }
+            chosen.push(i);
+            extend(values, &order[j + 1..], size - 1, target - v, chosen, out);
+            chosen.pop();
+        }
+    }
+
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by_key(|&i| Reverse(values[i]));
+
+    let mut out = vec![];
+    extend(values, &order, size, target, &mut vec![], &mut out);
+    out
+}
+
+/// The subsets of `values` with the fewest elements that sum to `target`.
+/// Returns an empty `Vec` if no subset reaches the target.
+pub fn min_size_subsets(values: &[u64], target: u64) -> Vec<Vec<usize>> {
+    (0..=values.len())
+        .map(|size| subsets_of_size(values, size, target))
+        .find(|subsets| !subsets.is_empty())
+        .unwrap_or_default()
+}
+
+/// Split `values` into `k` groups with equal sums, if possible.
+pub fn partition(values: &[u64], k: usize) -> Option<Vec<Vec<usize>>> {
+    fn assign(
+        values: &[u64],
+        order: &[usize],
+        target: u64,
+        sums: &mut [u64],
+        groups: &mut [usize],
+    ) -> bool {
+        let Some((&i, rest)) = order.split_first() else {
+            return true;
+        };
+
+        for g in 0..sums.len() {
+            if sums[g] + values[i] > target {
+                continue;
+            }
+            sums[g] += values[i];
+            groups[i] = g;
+            if assign(values, rest, target, sums, groups) {
+                return true;
+            }
+            sums[g] -= values[i];
+            // the groups are interchangeable, so placing the value into another empty group fails too.
+            if sums[g] == 0 {
+                break;
+            }
+        }
+
+        false
+    }
+
+    if k == 0 {
+        return values.is_empty().then(Vec::new);
+    }
+
+    let sum: u64 = values.iter().sum();
+    if !sum.is_multiple_of(k as u64) {
+        return None;
+    }
+    let target = sum / k as u64;
+
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by_key(|&i| Reverse(values[i]));
+
+    let mut groups = vec![0; values.len()];
+    if !assign(values, &order, target, &mut vec![0; k], &mut groups) {
+        return None;
+    }
+
+    let mut out = vec![vec![]; k];
+    for (i, g) in groups.into_iter().enumerate() {
+        out[g].push(i);
+    }
+    Some(out)
+}
+
+/// Whether `values` can be split into `k` groups with equal sums.
+pub fn can_partition(values: &[u64], k: usize) -> bool {
+    partition(values, k).is_some()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        can_partition, count_subsets, count_subsets_by_size, min_size_subsets, partition,
+        subsets_of_size,
+    };
+
+    const CONTAINERS: [u64; 5] = [20, 15, 10, 5, 5];
+
+    #[test]
+    fn counts_subsets() {
+        assert_eq!(count_subsets(&CONTAINERS, 25), 4);
+        assert_eq!(count_subsets(&CONTAINERS, 0), 1);
+        assert_eq!(count_subsets(&CONTAINERS, 100), 0);
+        assert_eq!(count_subsets_by_size(&CONTAINERS, 25), [0, 0, 3, 1, 0, 0]);
+    }
+
+    #[test]
+    fn enumerates_subsets() {
+        assert_eq!(
+            min_size_subsets(&CONTAINERS, 25),
+            [vec![0, 3], vec![0, 4], vec![1, 2]]
+        );
+        assert_eq!(subsets_of_size(&CONTAINERS, 3, 25), [vec![1, 3, 4]]);
+        assert!(min_size_subsets(&CONTAINERS, 100).is_empty());
+    }
+
+    #[test]
+    fn partitions_into_equal_groups() {
+        let values = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+        for k in [1, 2, 3, 4] {
+            let groups = partition(&values, k).unwrap();
+            assert_eq!(groups.len(), k);
+            for group in groups {
+                let sum: u64 = group.iter().map(|&i| values[i]).sum();
+                assert_eq!(sum, 60 / k as u64);
+            }
+        }
+        assert!(!can_partition(&values, 7));
+        // sums match, but 9 does not fit into a group of 6.
+        assert!(!can_partition(&[9, 1, 1, 1], 2));
+    }
+}
@@ -0,0 +1,161 @@
+//! Running a state transition many times, skipping ahead once the states repeat.
+//!
+//! [`iterate_with_cycle_detection`] uses Brent's algorithm, so only a few states are kept at a
+//! time. States are compared by a cached hash first and only checked for equality when the
+//! hashes match, which keeps the comparisons cheap for large states like grids.
+use std::hash::{BuildHasher, Hash, RandomState};
+
+/// A cycle in the sequence `x0, f(x0), f(f(x0)), ...`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The index of the first state that is part of the cycle.
+    pub start: usize,
+    /// The number of states in the cycle.
+    pub length: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Iteration<S> {
+    /// The state after the requested number of steps.
+    pub state: S,
+    /// `None` if the steps ran out before any state repeated.
+    pub cycle: Option<Cycle>,
+}
+
+struct Hashed<S> {
+    hash: u64,
+    state: S,
+}
+
+impl<S: Hash + Eq> Hashed<S> {
+    fn new(hasher: &RandomState, state: S) -> Self {
+        Hashed {
+            hash: hasher.hash_one(&state),
+            state,
+        }
+    }
+
+    fn same(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.state == other.state
+    }
+}
+
+/// Apply `step` to `start` `steps` times and return the final state. If a state repeats on
+/// the way, the cycle is measured and the remaining steps are skipped, so `steps` can be huge.
+pub fn iterate_with_cycle_detection<S, F>(start: S, steps: usize, mut step: F) -> Iteration<S>
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    if steps == 0 {
+        return Iteration {
+            state: start,
+            cycle: None,
+        };
+    }
+
+    let hasher = RandomState::new();
+    let mut next = |s: &Hashed<S>| Hashed::new(&hasher, step(&s.state));
+
+    // brent: the tortoise waits at powers of two while the hare runs ahead, so `length` is the
+    // distance between them once they meet.
+    let first = Hashed::new(&hasher, start);
+    let mut tortoise = Hashed::new(&hasher, first.state.clone());
+    let mut hare = next(&first);
+    let (mut done, mut power, mut length) = (1, 1, 1);
+    while !tortoise.same(&hare) {
+        if done == steps {
+            return Iteration {
+                state: hare.state,
+                cycle: None,
+            };
+        }
+        if power == length {
+            tortoise = Hashed::new(&hasher, hare.state.clone());
+            power *= 2;
+            length = 0;
+        }
+        hare = next(&hare);
+        done += 1;
+        length += 1;
+    }
+
+    // find the start of the cycle by running two states `length` apart from the beginning.
+    let mut tortoise = first;
+    let mut hare = Hashed::new(&hasher, tortoise.state.clone());
+    for _ in 0..length {
+        hare = next(&hare);
+    }
+    let mut start = 0;
+    while !tortoise.same(&hare) {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+
+    let mut state = tortoise;
+    for _ in 0..(steps - start) % length {
+        state = next(&state);
+    }
+    Iteration {
+        state: state.state,
+        cycle: Some(Cycle { start, length }),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{iterate_with_cycle_detection, Cycle};
+
+    fn naive(start: u64, steps: usize, f: impl Fn(u64) -> u64) -> u64 {
+        (0..steps).fold(start, |x, _| f(x))
+    }
+
+    #[test]
+    fn matches_naive_iteration() {
+        for modulus in [7, 97, 255, 1009] {
+            let f = |x: u64| (x * x + 1) % modulus;
+            for steps in [0, 1, 2, 5, 30, 1000, 12_345] {
+                let result = iterate_with_cycle_detection(3, steps, |&x| f(x));
+                assert_eq!(
+                    result.state,
+                    naive(3, steps, f),
+                    "{steps} steps mod {modulus}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reports_cycles() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2 -> ...
+        let f = |&x: &u64| if x == 4 { 2 } else { x + 1 };
+        let result = iterate_with_cycle_detection(0, 1_000_000_000_000, f);
+        assert_eq!(
+            result.cycle,
+            Some(Cycle {
+                start: 2,
+                length: 3
+            })
+        );
+        assert_eq!(result.state, 2 + (1_000_000_000_000 - 2) % 3);
+
+        let fixed = iterate_with_cycle_detection("22".to_string(), usize::MAX, |s| s.clone());
+        assert_eq!(
+            fixed.cycle,
+            Some(Cycle {
+                start: 0,
+                length: 1
+            })
+        );
+    }
+
+    #[test]
+    fn stops_without_a_cycle() {
+        let result = iterate_with_cycle_detection(0u64, 100, |&x| x + 1);
+        assert_eq!(result.state, 100);
+        assert_eq!(result.cycle, None);
+    }
+}
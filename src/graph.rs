@@ -0,0 +1,319 @@
+//! Weighted graphs over named nodes, and route solvers for them.
+//!
+//! [`GraphBuilder`] interns node names into indices `0..n` while edges are added, so puzzles
+//! like `London to Dublin = 464` can be read line by line:
+//!
+//! ```ignore
+//! let mut builder = GraphBuilder::new();
+//! builder.undirected("London", "Dublin", 464);
+//! let graph = builder.build();
+//! let route = graph.hamiltonian_path(Objective::Min).unwrap();
+//! println!("{} = {}", graph.route_names(&route).join(" -> "), route.cost);
+//! ```
+use std::{collections::HashMap, ops::Add};
+
+use crate::grid::Grid;
+
+/// Whether a solver looks for the cheapest or the most expensive route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    Min,
+    Max,
+}
+
+impl Objective {
+    /// Returns `true` if `a` is strictly better than `b`.
+    pub fn better<W: Ord>(self, a: W, b: W) -> bool {
+        match self {
+            Objective::Min => a < b,
+            Objective::Max => a > b,
+        }
+    }
+}
+
+/// A route through a [`Graph`], as node indices in the order they are visited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route<W> {
+    pub cost: W,
+    /// For cycles, the edge from the last node back to the first is implied.
+    pub nodes: Vec<usize>,
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug, Clone)]
+pub struct GraphBuilder<W> {
+    index: HashMap<String, usize>,
+    names: Vec<String>,
+    edges: HashMap<(usize, usize), W>,
+}
+
+impl<W> Default for GraphBuilder<W> {
+    fn default() -> Self {
+        GraphBuilder {
+            index: HashMap::new(),
+            names: vec![],
+            edges: HashMap::new(),
+        }
+    }
+}
+
+impl<W: Copy + Add<Output = W>> GraphBuilder<W> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the index of the node called `name`, adding it if it is new.
+    pub fn node(&mut self, name: &str) -> usize {
+        if let Some(&i) = self.index.get(name) {
+            return i;
+        }
+        self.names.push(name.to_string());
+        self.index.insert(name.to_string(), self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    /// The nodes added so far, by index.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Set the weight of the edge `from -> to`.
+    pub fn edge(&mut self, from: &str, to: &str, weight: W) {
+        let key = (self.node(from), self.node(to));
+        self.edges.insert(key, weight);
+    }
+
+    /// Set the weight of the edges `a -> b` and `b -> a`.
+    pub fn undirected(&mut self, a: &str, b: &str, weight: W) {
+        self.edge(a, b, weight);
+        self.edge(b, a, weight);
+    }
+
+    /// Add `weight` to the edge `from -> to`, creating it if it does not exist.
+    pub fn add_edge(&mut self, from: &str, to: &str, weight: W) {
+        let key = (self.node(from), self.node(to));
+        self.edges
+            .entry(key)
+            .and_modify(|w| *w = *w + weight)
+            .or_insert(weight);
+    }
+
+    /// Add `weight` to the edges `a -> b` and `b -> a`, creating them if they do not exist.
+    pub fn add_undirected(&mut self, a: &str, b: &str, weight: W) {
+        self.add_edge(a, b, weight);
+        self.add_edge(b, a, weight);
+    }
+
+    pub fn build(self) -> Graph<W> {
+        let n = self.names.len();
+        let mut weights = Grid::new(n, n, None);
+        for (pos, w) in self.edges {
+            weights[pos] = Some(w);
+        }
+        Graph {
+            names: self.names,
+            index: self.index,
+            weights,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A directed graph stored as an adjacency matrix. Undirected edges are stored in both directions.
+#[derive(Debug, Clone)]
+pub struct Graph<W> {
+    names: Vec<String>,
+    index: HashMap<String, usize>,
+    weights: Grid<Option<W>>,
+}
+
+impl<W: Copy> Graph<W> {
+    /// The number of nodes.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn name(&self, node: usize) -> &str {
+        &self.names[node]
+    }
+
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.index.get(name).copied()
+    }
+
+    /// The weight of the edge `from -> to`, if there is one.
+    pub fn weight(&self, from: usize, to: usize) -> Option<W> {
+        self.weights[(from, to)]
+    }
+
+    /// The nodes reachable from `node` with the weights of the edges to them.
+    pub fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, W)> + '_ {
+        self.weights
+            .row(node)
+            .iter()
+            .enumerate()
+            .filter_map(|(to, w)| Some((to, (*w)?)))
+    }
+
+    /// The adjacency matrix, with `missing` for pairs of nodes without an edge.
+    pub fn matrix(&self, missing: W) -> Grid<W> {
+        Grid::from_fn(self.len(), self.len(), |pos| {
+            self.weights[pos].unwrap_or(missing)
+        })
+    }
+
+    pub fn route_names(&self, route: &Route<W>) -> Vec<&str> {
+        route.nodes.iter().map(|&i| self.name(i)).collect()
+    }
+}
+
+impl<W: Copy + Ord + Default + Add<Output = W>> Graph<W> {
+    /// The best route visiting every node exactly once, starting and ending anywhere.
+    /// Returns `None` if the edges do not allow such a route.
+    ///
+    /// Uses Held-Karp, which takes `O(2^n * n^2)` time and `O(2^n * n)` memory.
+    pub fn hamiltonian_path(&self, objective: Objective) -> Option<Route<W>> {
+        self.held_karp(objective, false)
+    }
+
+    /// The best round trip visiting every node exactly once.
+    /// Returns `None` if the edges do not allow such a route.
+    ///
+    /// Uses Held-Karp, which takes `O(2^n * n^2)` time and `O(2^n * n)` memory.
+    pub fn hamiltonian_cycle(&self, objective: Objective) -> Option<Route<W>> {
+        self.held_karp(objective, true)
+    }
+
+    fn held_karp(&self, objective: Objective, cycle: bool) -> Option<Route<W>> {
+        let n = self.len();
+        if n == 0 {
+            return None;
+        }
+        assert!(n < 32, "held-karp is infeasible for {n} nodes");
+
+        let full = (1usize << n) - 1;
+        // `best[mask * n + v]` is the best cost of visiting the nodes in `mask`, ending at `v`.
+        let mut best: Vec<Option<W>> = vec![None; (full + 1) * n];
+        let mut parent = vec![usize::MAX; (full + 1) * n];
+
+        // a cycle can start anywhere, so fix it to the first node.
+        for start in 0..if cycle { 1 } else { n } {
+            best[(1 << start) * n + start] = Some(W::default());
+        }
+
+        for mask in 1..full {
+            for v in (0..n).filter(|v| mask & (1 << v) != 0) {
+                let Some(cost) = best[mask * n + v] else {
+                    continue;
+                };
+                for (u, w) in self.neighbors(v) {
+                    if mask & (1 << u) != 0 {
+                        continue;
+                    }
+                    let i = (mask | (1 << u)) * n + u;
+                    if best[i].is_none_or(|b| objective.better(cost + w, b)) {
+                        best[i] = Some(cost + w);
+                        parent[i] = v;
+                    }
+                }
+            }
+        }
+
+        let (end, cost) = (0..n)
+            .filter_map(|v| {
+                let cost = best[full * n + v]?;
+                if cycle {
+                    Some((v, cost + self.weight(v, 0)?))
+                } else {
+                    Some((v, cost))
+                }
+            })
+            .reduce(|a, b| if objective.better(b.1, a.1) { b } else { a })?;
+
+        let mut nodes = vec![end];
+        let (mut mask, mut v) = (full, end);
+        while parent[mask * n + v] != usize::MAX {
+            let prev = parent[mask * n + v];
+            mask ^= 1 << v;
+            v = prev;
+            nodes.push(v);
+        }
+        nodes.reverse();
+
+        Some(Route { cost, nodes })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{GraphBuilder, Objective};
+
+    fn cities() -> super::Graph<u64> {
+        let mut builder = GraphBuilder::new();
+        builder.undirected("London", "Dublin", 464);
+        builder.undirected("London", "Belfast", 518);
+        builder.undirected("Dublin", "Belfast", 141);
+        builder.build()
+    }
+
+    #[test]
+    fn interns_and_sums_edges() {
+        let mut builder = GraphBuilder::new();
+        builder.add_undirected("Alice", "Bob", 54);
+        builder.add_undirected("Bob", "Alice", 83);
+        builder.edge("Alice", "Carol", -79);
+        assert_eq!(builder.names(), ["Alice", "Bob", "Carol"]);
+
+        let graph = builder.build();
+        assert_eq!(graph.index("Carol"), Some(2));
+        assert_eq!(graph.weight(0, 1), Some(137));
+        assert_eq!(graph.weight(1, 0), Some(137));
+        assert_eq!(graph.weight(0, 2), Some(-79));
+        assert_eq!(graph.weight(2, 0), None);
+        assert_eq!(graph.matrix(0)[(2, 0)], 0);
+    }
+
+    #[test]
+    fn finds_hamiltonian_paths() {
+        let graph = cities();
+
+        let shortest = graph.hamiltonian_path(Objective::Min).unwrap();
+        assert_eq!(shortest.cost, 605);
+        let names = graph.route_names(&shortest);
+        assert!(
+            names == ["London", "Dublin", "Belfast"] || names == ["Belfast", "Dublin", "London"]
+        );
+
+        let longest = graph.hamiltonian_path(Objective::Max).unwrap();
+        assert_eq!(longest.cost, 982);
+        assert_eq!(longest.nodes[1], graph.index("London").unwrap());
+    }
+
+    #[test]
+    fn finds_hamiltonian_cycles() {
+        let mut builder = GraphBuilder::new();
+        for (a, b, w) in [("a", "b", 1), ("b", "c", 1), ("c", "d", 1), ("d", "a", 1)] {
+            builder.undirected(a, b, w);
+        }
+        builder.undirected("a", "c", 5);
+        let graph = builder.build();
+
+        let cheapest = graph.hamiltonian_cycle(Objective::Min).unwrap();
+        assert_eq!(cheapest.cost, 4);
+        assert_eq!(cheapest.nodes.len(), 4);
+        assert_eq!(cheapest.nodes[0], 0);
+
+        let mut builder = GraphBuilder::new();
+        builder.edge("a", "b", 1);
+        builder.edge("b", "c", 1);
+        assert!(builder.build().hamiltonian_cycle(Objective::Min).is_none());
+    }
+}
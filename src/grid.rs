@@ -0,0 +1,457 @@
+//! Two-dimensional grids for puzzles played on a map.
+//!
+//! [`Grid`] stores a fixed-size rectangle of cells in row-major order and is indexed by
+//! `(row, col)`. [`SparseGrid`] holds cells at arbitrary signed positions, for maps that grow
+//! in every direction.
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    ops::{Bound, Index, IndexMut, Range, RangeBounds},
+};
+
+/// A position in a [`Grid`], as `(row, col)`.
+pub type Pos = (usize, usize);
+
+pub const DIRS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+#[rustfmt::skip]
+pub const DIRS_8: [(isize, isize); 8] = [
+    (-1, -1), (-1, 0), (-1, 1),
+    (0, -1),           (0, 1),
+    (1, -1),  (1, 0),  (1, 1),
+];
+
+/// An error which can be returned when parsing a character map whose rows differ in length.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridError {
+    /// 1-based line of the input.
+    pub line: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}: expected {} cells, found {}",
+            self.line, self.expected, self.found
+        )
+    }
+}
+
+impl Error for GridError {}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(rows: usize, cols: usize, value: T) -> Self {
+        Grid {
+            rows,
+            cols,
+            cells: vec![value; rows * cols],
+        }
+    }
+
+    /// Set every cell to `value`.
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..rows * cols).map(|i| f((i / cols, i % cols))).collect();
+        Grid { rows, cols, cells }
+    }
+
+    /// Parse a character map, converting each character with `f`. Empty lines are skipped.
+    pub fn try_parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        let mut cells = vec![];
+        let (mut rows, mut cols) = (0, 0);
+
+        for (i, line) in input.lines().enumerate().filter(|(_, l)| !l.is_empty()) {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let found = cells.len() - before;
+
+            if rows == 0 {
+                cols = found;
+            } else if found != cols {
+                return Err(GridError {
+                    line: i + 1,
+                    expected: cols,
+                    found,
+                });
+            }
+            rows += 1;
+        }
+
+        Ok(Grid { rows, cols, cells })
+    }
+
+    /// Parse a character map, converting each character with `f`. Empty lines are skipped.
+    ///
+    /// # Panics
+    /// If the lines of the map differ in length.
+    pub fn parse(input: &str, f: impl FnMut(char) -> T) -> Self {
+        Self::try_parse(input, f).unwrap_or_else(|e| panic!("failed to parse grid: {e}"))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, (r, c): Pos) -> bool {
+        r < self.rows && c < self.cols
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.cols + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.0 * self.cols + pos.1])
+    }
+
+    /// Returns the cell at `pos` moved by `(dr, dc)`, if it is inside the grid.
+    pub fn step(&self, (r, c): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let pos = (r.checked_add_signed(dr)?, c.checked_add_signed(dc)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Returns the cell at `pos` moved by `(dr, dc)`, wrapping around the edges.
+    pub fn step_wrapping(&self, (r, c): Pos, (dr, dc): (isize, isize)) -> Pos {
+        let wrap = |x: usize, d: isize, n: usize| (x as isize + d).rem_euclid(n as isize) as usize;
+        (wrap(r, dr, self.rows), wrap(c, dc, self.cols))
+    }
+
+    /// The orthogonal neighbours of `pos` inside the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS_4.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` inside the grid.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS_8.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    /// The orthogonal neighbours of `pos`, wrapping around the edges.
+    /// Grids narrower than 3 cells yield some neighbours more than once.
+    pub fn neighbors4_wrapping(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS_4.iter().map(move |&d| self.step_wrapping(pos, d))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos`, wrapping around the edges.
+    /// Grids narrower than 3 cells yield some neighbours more than once.
+    pub fn neighbors8_wrapping(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS_8.iter().map(move |&d| self.step_wrapping(pos, d))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.cells.len()).map(move |i| (i / cols, i % cols))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// The cells with their positions, in row-major order.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.cols..(r + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, r: usize) -> &mut [T] {
+        &mut self.cells[r * self.cols..(r + 1) * self.cols]
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    /// The part of each row in `rows` that lies in `cols`, e.g. `grid.rect(0..=2, 3..)`.
+    ///
+    /// # Panics
+    /// If the ranges exceed the grid.
+    pub fn rect(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> impl Iterator<Item = &[T]> {
+        let cols = to_range(cols, self.cols);
+        to_range(rows, self.rows).map(move |r| &self.row(r)[cols.clone()])
+    }
+
+    /// The part of each row in `rows` that lies in `cols`, e.g. `grid.rect_mut(0..=2, 3..)`.
+    ///
+    /// # Panics
+    /// If the ranges exceed the grid.
+    pub fn rect_mut(
+        &mut self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> impl Iterator<Item = &mut [T]> {
+        let (rows, cols) = (to_range(rows, self.rows), to_range(cols, self.cols));
+        let width = self.cols.max(1);
+        self.cells
+            .chunks_mut(width)
+            .skip(rows.start)
+            .take(rows.len())
+            .map(move |row| &mut row[cols.clone()])
+    }
+
+    /// Draw the grid with one character per cell.
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        let mut out = String::with_capacity(self.rows * (self.cols + 1));
+        for row in self.iter_rows() {
+            out.extend(row.iter().map(&f));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+fn to_range(bounds: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match bounds.start_bound() {
+        Bound::Included(&x) => x,
+        Bound::Excluded(&x) => x + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match bounds.end_bound() {
+        Bound::Included(&x) => x + 1,
+        Bound::Excluded(&x) => x,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end && end <= len,
+        "range {start}..{end} out of bounds for length {len}"
+    );
+    start..end
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (r, c): Pos) -> &Self::Output {
+        assert!(self.contains((r, c)), "({r}, {c}) is outside the grid");
+        &self.cells[r * self.cols + c]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (r, c): Pos) -> &mut Self::Output {
+        assert!(self.contains((r, c)), "({r}, {c}) is outside the grid");
+        &mut self.cells[r * self.cols + c]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.iter_rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A grid without bounds, storing only the cells that were set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, pos: (i64, i64)) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: (i64, i64)) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    /// Returns the previous value of the cell.
+    pub fn insert(&mut self, pos: (i64, i64), value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: (i64, i64)) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    /// Returns the cell at `pos`, setting it with `f` first if it is empty.
+    pub fn entry_or_insert_with(&mut self, pos: (i64, i64), f: impl FnOnce() -> T) -> &mut T {
+        self.cells.entry(pos).or_insert_with(f)
+    }
+
+    /// The number of cells that are set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.cells.iter().map(|(&pos, v)| (pos, v))
+    }
+
+    /// The smallest rectangle containing every set cell, as `(min, max)` corners.
+    pub fn bounds(&self) -> Option<((i64, i64), (i64, i64))> {
+        self.cells.keys().fold(None, |acc, &(r, c)| {
+            let ((r0, c0), (r1, c1)) = acc.unwrap_or(((r, c), (r, c)));
+            Some(((r0.min(r), c0.min(c)), (r1.max(r), c1.max(c))))
+        })
+    }
+
+    /// Draw the bounding rectangle with one character per cell, `None` for empty cells.
+    pub fn render(&self, f: impl Fn(Option<&T>) -> char) -> String {
+        let Some(((r0, c0), (r1, c1))) = self.bounds() else {
+            return String::new();
+        };
+        let mut out = String::new();
+        for r in r0..=r1 {
+            out.extend((c0..=c1).map(|c| f(self.get((r, c)))));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(((r0, c0), (r1, c1))) = self.bounds() else {
+            return Ok(());
+        };
+        for r in r0..=r1 {
+            for c in c0..=c1 {
+                match self.get((r, c)) {
+                    Some(cell) => write!(f, "{cell}")?,
+                    None => write!(f, " ")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// The orthogonal neighbours of a [`SparseGrid`] position.
+pub fn neighbors4((r, c): (i64, i64)) -> impl Iterator<Item = (i64, i64)> {
+    DIRS_4
+        .iter()
+        .map(move |&(dr, dc)| (r + dr as i64, c + dc as i64))
+}
+
+/// The orthogonal and diagonal neighbours of a [`SparseGrid`] position.
+pub fn neighbors8((r, c): (i64, i64)) -> impl Iterator<Item = (i64, i64)> {
+    DIRS_8
+        .iter()
+        .map(move |&(dr, dc)| (r + dr as i64, c + dc as i64))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{neighbors4, neighbors8, Grid, GridError, SparseGrid};
+
+    const MAP: &str = ".#.#.#\n...##.\n#....#\n..#...\n#.#..#\n####..\n";
+
+    #[test]
+    fn parses_and_renders() {
+        let grid = Grid::parse(MAP, |c| c == '#');
+        assert_eq!((grid.rows(), grid.cols()), (6, 6));
+        assert!(grid[(0, 1)] && !grid[(0, 0)]);
+        assert_eq!(grid.render(|&b| if b { '#' } else { '.' }), MAP);
+
+        let digits = Grid::parse("12\n34", |c| c.to_digit(10).unwrap());
+        assert_eq!(digits.to_string(), "12\n34\n");
+        assert_eq!(
+            Grid::try_parse("..\n...", |c| c).unwrap_err(),
+            GridError {
+                line: 2,
+                expected: 2,
+                found: 3
+            }
+        );
+    }
+
+    #[test]
+    fn iterates_neighbourhoods() {
+        let grid = Grid::new(3, 4, 0);
+        assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8((2, 3)).count(), 3);
+        assert_eq!(grid.neighbors4((0, 2)).count(), 3);
+
+        let mut wrapped: Vec<_> = grid.neighbors4_wrapping((0, 0)).collect();
+        wrapped.sort_unstable();
+        assert_eq!(wrapped, [(0, 1), (0, 3), (1, 0), (2, 0)]);
+        assert_eq!(grid.step((0, 0), (-1, 0)), None);
+    }
+
+    #[test]
+    fn slices_rows_and_rects() {
+        let mut grid = Grid::from_fn(4, 5, |(r, c)| r * 10 + c);
+        assert_eq!(grid.row(2), [20, 21, 22, 23, 24]);
+
+        let rect: Vec<&[usize]> = grid.rect(1..=2, 3..).collect();
+        assert_eq!(rect, [&[13, 14][..], &[23, 24]]);
+
+        for row in grid.rect_mut(..2, 1..=1) {
+            row[0] = 0;
+        }
+        assert_eq!(grid.iter().filter(|&&x| x == 0).count(), 3);
+    }
+
+    #[test]
+    fn grows_without_bounds() {
+        let mut grid = SparseGrid::new();
+        grid.insert((0, 0), 'a');
+        grid.insert((-2, 3), 'b');
+        assert_eq!(grid.bounds(), Some(((-2, 0), (0, 3))));
+        assert_eq!(grid.render(|c| *c.unwrap_or(&'.')), "...b\n....\na...\n");
+        assert_eq!(neighbors8((0, 0)).count(), 8);
+        assert!(neighbors4((0, 0)).all(|p| neighbors8((0, 0)).any(|q| q == p)));
+    }
+}
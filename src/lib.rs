@@ -1,3 +1,22 @@
+// lets the solution modules included by `solutions` refer to the crate by name, like the binaries do.
+#[cfg(feature = "serve")]
+extern crate self as advent_of_code;
+
+#[cfg(all(feature = "serve", feature = "dhat-heap"))]
+compile_error!("the `serve` and `dhat-heap` features are mutually exclusive");
+
+pub mod combinatorics;
+pub mod cycle;
+pub mod graph;
+pub mod grid;
+pub mod number;
+pub mod optim;
+pub mod parse;
+pub mod rect;
+pub mod scan;
+pub mod search;
+#[cfg(feature = "serve")]
+pub mod solutions;
 pub mod template;
 
 // Use this file to add helper functions and additional modules.
@@ -1,13 +1,18 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, next, progress, read, refresh, scaffold, site, solve, start, status, submit,
+    test, time, tui,
+};
 use args::{parse, AppArguments};
 
+#[cfg(feature = "serve")]
+use advent_of_code::template::commands::serve;
 #[cfg(feature = "today")]
 use advent_of_code::template::Day;
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{Day, DaySet, Format};
     use std::process;
 
     pub enum AppArguments {
@@ -16,25 +21,62 @@ mod args {
         },
         Read {
             day: Day,
+            part: Option<u8>,
         },
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
+            dry_run: bool,
+            template: Option<String>,
         },
         Solve {
-            day: Day,
+            day: Option<Day>,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            record: bool,
+            watch: bool,
+        },
+        Next,
+        Start {
+            day: Option<Day>,
+        },
+        Submit {
+            day: Option<Day>,
+            part: Option<u8>,
+        },
+        Test {
+            day: Option<Day>,
+            part: Option<u8>,
+            watch: bool,
+        },
+        Refresh {
+            day: Option<Day>,
         },
         All {
+            days: Option<DaySet>,
             release: bool,
+            format: Format,
         },
         Time {
             all: bool,
-            day: Option<Day>,
+            days: Option<DaySet>,
             store: bool,
+            format: Format,
+        },
+        Progress,
+        Status {
+            days: Option<DaySet>,
+            test: bool,
+        },
+        Site {
+            out: Option<String>,
+        },
+        Tui,
+        #[cfg(feature = "serve")]
+        Serve {
+            port: Option<u16>,
         },
         #[cfg(feature = "today")]
         Today,
@@ -46,33 +88,71 @@ mod args {
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                days: args.opt_free_from_str()?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let format = args.opt_value_from_str("--format")?.unwrap_or_default();
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    days: args.opt_free_from_str()?,
                     store,
+                    format,
                 }
             }
+            Some("progress") => AppArguments::Progress,
+            Some("status") => AppArguments::Status {
+                test: args.contains("--test"),
+                days: args.opt_free_from_str()?,
+            },
+            Some("site") => AppArguments::Site {
+                out: args.opt_value_from_str("--out")?,
+            },
+            Some("tui") => AppArguments::Tui,
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
+                part: args.opt_value_from_str("--part")?,
                 day: args.free_from_str()?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                dry_run: args.contains("--dry-run"),
+                template: args.opt_value_from_str("--template")?,
+                day: args.free_from_str()?,
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                record: args.contains("--record"),
+                watch: args.contains("--watch"),
+                day: args.opt_free_from_str()?,
+            },
+            Some("next") => AppArguments::Next,
+            Some("start") => AppArguments::Start {
+                day: args.opt_free_from_str()?,
+            },
+            Some("submit") => AppArguments::Submit {
+                part: args.opt_value_from_str("--part")?,
+                day: args.opt_free_from_str()?,
+            },
+            Some("test") => AppArguments::Test {
+                watch: args.contains("--watch"),
+                day: args.opt_free_from_str()?,
+                part: args.opt_free_from_str()?,
+            },
+            Some("refresh") => AppArguments::Refresh {
+                day: args.opt_free_from_str()?,
+            },
+            #[cfg(feature = "serve")]
+            Some("serve") => AppArguments::Serve {
+                port: args.opt_value_from_str("--port")?,
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -102,33 +182,71 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::All {
+                days,
+                release,
+                format,
+            } => all::handle(days, release, format),
+            AppArguments::Time {
+                days,
+                all,
+                store,
+                format,
+            } => time::handle(days, all, store, format),
+            AppArguments::Progress => progress::handle(),
+            AppArguments::Status { days, test } => status::handle(days, test),
+            AppArguments::Site { out } => site::handle(out.as_deref()),
+            AppArguments::Tui => tui::handle(),
             AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::Read { day, part } => read::handle(day, part),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                dry_run,
+                template,
             } => {
-                scaffold::handle(day, overwrite);
-                if download {
+                // download first, so the template can use the puzzle title.
+                if download && !dry_run {
                     download::handle(day);
                 }
+                scaffold::handle(day, template.as_deref(), overwrite, dry_run);
             }
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                record,
+                watch,
+            } => {
+                let day = day.unwrap_or_else(next::next_day);
+                if watch {
+                    solve::handle_watch(day, release, dhat);
+                }
+                solve::handle(day, release, dhat, submit);
+                if record {
+                    time::confirm_record(day);
+                }
+            }
+            AppArguments::Next => next::handle(),
+            AppArguments::Start { day } => start::handle(day.unwrap_or_else(next::next_day)),
+            AppArguments::Submit { day, part } => {
+                submit::handle(day.unwrap_or_else(next::next_day), part);
+            }
+            AppArguments::Test { day, part, watch } => {
+                test::handle(day.unwrap_or_else(next::next_day), part, watch);
+            }
+            AppArguments::Refresh { day } => refresh::handle(day.unwrap_or_else(next::next_day)),
+            #[cfg(feature = "serve")]
+            AppArguments::Serve { port } => serve::handle(port),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
                         download::handle(day);
-                        read::handle(day)
+                        scaffold::handle(day, None, false, false);
+                        read::handle(day, None)
                     }
                     None => {
                         eprintln!(
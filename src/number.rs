@@ -0,0 +1,261 @@
+//! Number theory helpers: modular arithmetic, divisor sums and diagonal indexing.
+//!
+//! Modular functions take `u64` operands and multiply in `u128`, so any modulus below `2^64`
+//! is safe from overflow.
+use std::collections::HashMap;
+
+fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 * b as u128) % modulus as u128) as u64
+}
+
+/// `base^exp mod modulus`, by repeated squaring.
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
+
+/// The inverse of `a` modulo `modulus`, if `a` and `modulus` are coprime.
+pub fn mod_inv(a: u64, modulus: u64) -> Option<u64> {
+    // extended euclid, tracking only the coefficient of `a`.
+    let (mut r0, mut r1) = (modulus as i128, (a % modulus) as i128);
+    let (mut t0, mut t1) = (0i128, 1i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+    (r0 == 1).then(|| t0.rem_euclid(modulus as i128) as u64)
+}
+
+/// The smallest `x` with `base^x ≡ target (mod modulus)`, by baby-step giant-step in
+/// `O(sqrt(modulus))` time and memory. Returns `None` if there is no such `x` or if `base`
+/// and `modulus` are not coprime.
+pub fn discrete_log(base: u64, target: u64, modulus: u64) -> Option<u64> {
+    let target = target % modulus;
+    let m = (modulus as f64).sqrt().ceil() as u64;
+
+    // baby steps: `base^j -> j`, keeping the smallest `j` for each value.
+    let mut table = HashMap::with_capacity(m as usize);
+    let mut value = 1 % modulus;
+    for j in 0..m {
+        table.entry(value).or_insert(j);
+        value = mul_mod(value, base, modulus);
+    }
+
+    // giant steps: `target * base^(-m*i)`.
+    let factor = mod_inv(mod_pow(base, m, modulus), modulus)?;
+    let mut gamma = target;
+    for i in 0..m {
+        if let Some(&j) = table.get(&gamma) {
+            return Some(i * m + j);
+        }
+        gamma = mul_mod(gamma, factor, modulus);
+    }
+
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The prime factors of `n` with their exponents, in ascending order.
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = vec![];
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            let mut exp = 0;
+            while n.is_multiple_of(p) {
+                n /= p;
+                exp += 1;
+            }
+            factors.push((p, exp));
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+/// The sum of the divisors of `n`, from its factorization.
+pub fn sigma(n: u64) -> u64 {
+    factorize(n)
+        .into_iter()
+        .map(|(p, exp)| (p.pow(exp + 1) - 1) / (p - 1))
+        .product()
+}
+
+/// The sum of the divisors of every `n` in `0..=limit`, with `sigma[0] = 0`.
+pub fn sigma_sieve(limit: usize) -> Vec<u64> {
+    sigma_sieve_limited(limit, usize::MAX)
+}
+
+/// Like [`sigma_sieve`], but each divisor `d` only counts towards its first `multiples`
+/// multiples, i.e. for `n` up to `multiples * d`.
+pub fn sigma_sieve_limited(limit: usize, multiples: usize) -> Vec<u64> {
+    let mut sums = vec![0u64; limit + 1];
+    for d in 1..=limit {
+        for n in (d..=limit).step_by(d).take(multiples) {
+            sums[n] += d as u64;
+        }
+    }
+    sums
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The `n`-th triangular number, `1 + 2 + ... + n`.
+pub fn triangular(n: u64) -> u64 {
+    n * (n + 1) / 2
+}
+
+/// The 1-based position of `(row, col)` when a grid is filled diagonal by diagonal, starting
+/// at `(1, 1)` and running up and to the right:
+///
+/// ```text
+///    | 1   2   3
+/// ---+---------
+///  1 | 1   3   6
+///  2 | 2   5
+///  3 | 4
+/// ```
+pub fn diagonal_index(row: u64, col: u64) -> u64 {
+    triangular(row + col - 2) + col
+}
+
+/// The `(row, col)` at 1-based `index` in the order of [`diagonal_index`].
+///
+/// # Panics
+/// If `index` is 0.
+pub fn diagonal_position(index: u64) -> (u64, u64) {
+    assert!(index > 0, "diagonal indices start at 1");
+    // the diagonal `d` holds the indices `T(d - 1) + 1..=T(d)`.
+    let mut d = ((((8 * index) as f64).sqrt() - 1.0) / 2.0) as u64;
+    while triangular(d) < index {
+        d += 1;
+    }
+    while d > 0 && triangular(d - 1) >= index {
+        d -= 1;
+    }
+    let col = index - triangular(d - 1);
+    (d + 1 - col, col)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::{
+        diagonal_index, diagonal_position, discrete_log, factorize, mod_inv, mod_pow, sigma,
+        sigma_sieve, sigma_sieve_limited,
+    };
+
+    fn rng() -> StdRng {
+        StdRng::seed_from_u64(2015)
+    }
+
+    fn gcd(a: u64, b: u64) -> u64 {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    #[test]
+    fn mod_pow_matches_repeated_multiplication() {
+        let mut rng = rng();
+        for _ in 0..500 {
+            let (base, exp, modulus) = (
+                rng.random_range(0..10_000),
+                rng.random_range(0..200),
+                rng.random_range(1..1_000),
+            );
+            let expected = (0..exp).fold(1 % modulus, |acc, _| acc * base % modulus);
+            assert_eq!(
+                mod_pow(base, exp, modulus),
+                expected,
+                "{base}^{exp} % {modulus}"
+            );
+        }
+        assert_eq!(mod_pow(252533, 2, 33554393) * 20151125 % 33554393, 18749137);
+        assert_eq!(mod_pow(u64::MAX, 2, u64::MAX - 1), 1);
+    }
+
+    #[test]
+    fn mod_inv_matches_brute_force() {
+        let mut rng = rng();
+        for _ in 0..500 {
+            let (a, modulus) = (rng.random_range(0..500), rng.random_range(2..500));
+            let expected = (1..modulus).find(|x| a * x % modulus == 1);
+            assert_eq!(mod_inv(a, modulus), expected, "{a}^-1 % {modulus}");
+            assert_eq!(expected.is_some(), gcd(a, modulus) == 1);
+        }
+    }
+
+    #[test]
+    fn discrete_log_matches_brute_force() {
+        let mut rng = rng();
+        for _ in 0..300 {
+            let modulus = rng.random_range(2..2_000);
+            let base = rng.random_range(1..modulus);
+            if gcd(base, modulus) != 1 {
+                continue;
+            }
+            let target = rng.random_range(0..modulus);
+            let expected = (0..modulus).find(|&x| mod_pow(base, x, modulus) == target);
+            assert_eq!(
+                discrete_log(base, target, modulus),
+                expected,
+                "log_{base} {target} % {modulus}"
+            );
+        }
+        let target = mod_pow(252533, 123_456, 33554393);
+        assert_eq!(discrete_log(252533, target, 33554393), Some(123_456));
+    }
+
+    #[test]
+    fn sigma_matches_brute_force() {
+        let sieve = sigma_sieve(2_000);
+        let limited = sigma_sieve_limited(2_000, 50);
+        for n in 1..=2_000u64 {
+            let divisors = (1..=n).filter(|d| n % d == 0);
+            assert_eq!(
+                sieve[n as usize],
+                divisors.clone().sum::<u64>(),
+                "sigma({n})"
+            );
+            assert_eq!(sigma(n), sieve[n as usize], "sigma({n})");
+            let visited: u64 = divisors.filter(|d| n / d <= 50).sum();
+            assert_eq!(limited[n as usize], visited, "limited sigma({n})");
+
+            let product: u64 = factorize(n).iter().map(|&(p, e)| p.pow(e)).product();
+            assert_eq!(product, n);
+        }
+    }
+
+    #[test]
+    fn diagonal_indexing_round_trips() {
+        let mut expected = 1;
+        for diagonal in 1..60 {
+            for col in 1..=diagonal {
+                let row = diagonal + 1 - col;
+                assert_eq!(diagonal_index(row, col), expected, "({row}, {col})");
+                assert_eq!(diagonal_position(expected), (row, col));
+                expected += 1;
+            }
+        }
+        assert_eq!(diagonal_index(3, 4), 19);
+    }
+}
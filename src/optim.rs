@@ -0,0 +1,812 @@
+//! Linear and integer programs behind one model-building API, with interchangeable backends.
+//!
+//! Build a [`Model`] from variables, linear [`Expr`]essions and [`Constraint`]s, then solve it
+//! with any [`Backend`]:
+//!
+//! - [`Backend::BruteForce`] enumerates every integer point inside the variable bounds, pruning
+//!   with the constraints. It is exact and needs no external solver, which makes it a good
+//!   cross-check for small models.
+//! - [`Backend::Clarabel`] solves the continuous relaxation with the clarabel interior point
+//!   solver, ignoring integrality.
+//! - [`Backend::Cbc`] hands the model to CBC through `good_lp`. It needs the `cbc` feature
+//!   (enabled by default), which links against the native CBC library.
+//!
+//! ```ignore
+//! let mut model = Model::new(Objective::Max);
+//! let (x, y) = (model.integer(0, 3), model.integer(0, 3));
+//! model.set_objective(x * 3.0 + y * 2.0);
+//! model.add((x + y).at_most(4.0));
+//! let solution = model.solve(Backend::BruteForce)?;
+//! assert_eq!(solution.objective, 11.0);
+//! ```
+use std::{
+    error::Error,
+    fmt::Display,
+    iter::Sum,
+    ops::{Add, Mul},
+};
+
+use argmin::{
+    core::{CostFunction, Executor, Gradient, Hessian, State},
+    solver::{linesearch::MoreThuenteLineSearch, newton::NewtonCG},
+};
+use clarabel::{
+    algebra::CscMatrix,
+    solver::{DefaultSettings, DefaultSolver, IPSolver, SolverStatus, SupportedConeT},
+};
+use nalgebra::{DMatrix, DVector};
+
+pub use crate::graph::Objective;
+
+/// How far a value may be off and still count as satisfying a constraint or bound.
+const EPS: f64 = 1e-6;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OptimError {
+    Infeasible,
+    Unbounded,
+    /// The backend cannot handle the model, e.g. brute force over an unbounded variable.
+    Unsupported(&'static str),
+    /// The backend failed for another reason.
+    Solver(String),
+}
+
+impl Display for OptimError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OptimError::Infeasible => write!(f, "the model is infeasible"),
+            OptimError::Unbounded => write!(f, "the model is unbounded"),
+            OptimError::Unsupported(why) => write!(f, "unsupported model: {why}"),
+            OptimError::Solver(why) => write!(f, "solver failed: {why}"),
+        }
+    }
+}
+
+impl Error for OptimError {}
+
+/* -------------------------------------------------------------------------- */
+
+/// A variable of a [`Model`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Var(usize);
+
+impl Var {
+    /// The position of the variable in [`Solution::values`].
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A linear expression `Σ coefficient * var + constant`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Expr {
+    terms: Vec<(Var, f64)>,
+    constant: f64,
+}
+
+impl Expr {
+    pub fn constant(constant: f64) -> Self {
+        Expr {
+            terms: vec![],
+            constant,
+        }
+    }
+
+    /// The sum of `vars`, each with coefficient 1.
+    pub fn sum(vars: impl IntoIterator<Item = Var>) -> Self {
+        vars.into_iter().map(Expr::from).sum()
+    }
+
+    /// Add `coefficient * var`.
+    pub fn add_term(&mut self, var: Var, coefficient: f64) {
+        self.terms.push((var, coefficient));
+    }
+
+    /// The value of the expression for the variable values `values`.
+    pub fn eval(&self, values: &[f64]) -> f64 {
+        self.constant
+            + self
+                .terms
+                .iter()
+                .map(|&(v, c)| c * values[v.0])
+                .sum::<f64>()
+    }
+
+    pub fn at_most(self, rhs: f64) -> Constraint {
+        Constraint::new(self, Cmp::Le, rhs)
+    }
+
+    pub fn at_least(self, rhs: f64) -> Constraint {
+        Constraint::new(self, Cmp::Ge, rhs)
+    }
+
+    pub fn equals(self, rhs: f64) -> Constraint {
+        Constraint::new(self, Cmp::Eq, rhs)
+    }
+
+    /// The coefficients of the `len` variables and the constant, with repeated terms summed.
+    fn dense(&self, len: usize) -> (Vec<f64>, f64) {
+        let mut coefficients = vec![0.0; len];
+        for &(v, c) in &self.terms {
+            coefficients[v.0] += c;
+        }
+        (coefficients, self.constant)
+    }
+}
+
+impl From<Var> for Expr {
+    fn from(var: Var) -> Self {
+        Expr {
+            terms: vec![(var, 1.0)],
+            constant: 0.0,
+        }
+    }
+}
+
+impl<E: Into<Expr>> Add<E> for Expr {
+    type Output = Expr;
+
+    fn add(mut self, other: E) -> Expr {
+        let other = other.into();
+        self.terms.extend(other.terms);
+        self.constant += other.constant;
+        self
+    }
+}
+
+impl<E: Into<Expr>> Add<E> for Var {
+    type Output = Expr;
+
+    fn add(self, other: E) -> Expr {
+        Expr::from(self) + other
+    }
+}
+
+impl Mul<f64> for Var {
+    type Output = Expr;
+
+    fn mul(self, coefficient: f64) -> Expr {
+        Expr {
+            terms: vec![(self, coefficient)],
+            constant: 0.0,
+        }
+    }
+}
+
+impl Mul<f64> for Expr {
+    type Output = Expr;
+
+    fn mul(mut self, factor: f64) -> Expr {
+        self.terms.iter_mut().for_each(|(_, c)| *c *= factor);
+        self.constant *= factor;
+        self
+    }
+}
+
+impl Sum for Expr {
+    fn sum<I: Iterator<Item = Expr>>(iter: I) -> Self {
+        iter.fold(Expr::default(), Add::add)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cmp {
+    Le,
+    Ge,
+    Eq,
+}
+
+/// `expr <= rhs`, `expr >= rhs` or `expr == rhs`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constraint {
+    pub expr: Expr,
+    pub cmp: Cmp,
+    pub rhs: f64,
+}
+
+impl Constraint {
+    pub fn new(expr: Expr, cmp: Cmp, rhs: f64) -> Self {
+        Constraint { expr, cmp, rhs }
+    }
+
+    /// The range `expr` has to lie in.
+    fn range(&self) -> (f64, f64) {
+        match self.cmp {
+            Cmp::Le => (f64::NEG_INFINITY, self.rhs),
+            Cmp::Ge => (self.rhs, f64::INFINITY),
+            Cmp::Eq => (self.rhs, self.rhs),
+        }
+    }
+
+    pub fn is_satisfied(&self, values: &[f64]) -> bool {
+        let (lo, hi) = self.range();
+        let value = self.expr.eval(values);
+        lo - EPS <= value && value <= hi + EPS
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct VarDef {
+    integer: bool,
+    lower: f64,
+    upper: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    BruteForce,
+    Clarabel,
+    #[cfg(feature = "cbc")]
+    Cbc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    /// The value of every variable, by [`Var::index`].
+    pub values: Vec<f64>,
+    pub objective: f64,
+}
+
+impl Solution {
+    pub fn value(&self, var: Var) -> f64 {
+        self.values[var.0]
+    }
+
+    pub fn eval(&self, expr: &Expr) -> f64 {
+        expr.eval(&self.values)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Model {
+    sense: Objective,
+    vars: Vec<VarDef>,
+    objective: Expr,
+    constraints: Vec<Constraint>,
+}
+
+impl Model {
+    /// An empty model with a zero objective, to be minimised or maximised.
+    pub fn new(sense: Objective) -> Self {
+        Model {
+            sense,
+            vars: vec![],
+            objective: Expr::default(),
+            constraints: vec![],
+        }
+    }
+
+    fn var(&mut self, integer: bool, lower: f64, upper: f64) -> Var {
+        self.vars.push(VarDef {
+            integer,
+            lower,
+            upper,
+        });
+        Var(self.vars.len() - 1)
+    }
+
+    /// A real variable in `lower..=upper`. Either bound may be infinite.
+    pub fn continuous(&mut self, lower: f64, upper: f64) -> Var {
+        self.var(false, lower, upper)
+    }
+
+    /// An integer variable in `lower..=upper`.
+    pub fn integer(&mut self, lower: i64, upper: i64) -> Var {
+        self.var(true, lower as f64, upper as f64)
+    }
+
+    /// An integer variable in `0..=1`.
+    pub fn binary(&mut self) -> Var {
+        self.integer(0, 1)
+    }
+
+    /// The number of variables.
+    pub fn len(&self) -> usize {
+        self.vars.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vars.is_empty()
+    }
+
+    pub fn set_objective(&mut self, objective: impl Into<Expr>) {
+        self.objective = objective.into();
+    }
+
+    pub fn add(&mut self, constraint: Constraint) {
+        self.constraints.push(constraint);
+    }
+
+    /// Add one constraint per row of `a`: `a[i] · vars <cmp> rhs[i]`.
+    pub fn add_rows(&mut self, vars: &[Var], a: &DMatrix<f64>, cmp: Cmp, rhs: &DVector<f64>) {
+        for (row, &rhs) in a.row_iter().zip(rhs.iter()) {
+            let expr = Expr {
+                terms: vars.iter().copied().zip(row.iter().copied()).collect(),
+                constant: 0.0,
+            };
+            self.add(Constraint::new(expr, cmp, rhs));
+        }
+    }
+
+    /// Whether `values` respects every bound, integrality and constraint.
+    pub fn is_feasible(&self, values: &[f64]) -> bool {
+        let in_bounds = self.vars.iter().zip(values).all(|(def, &x)| {
+            def.lower - EPS <= x
+                && x <= def.upper + EPS
+                && (!def.integer || (x - x.round()).abs() <= EPS)
+        });
+        in_bounds && self.constraints.iter().all(|c| c.is_satisfied(values))
+    }
+
+    pub fn solve(&self, backend: Backend) -> Result<Solution, OptimError> {
+        match backend {
+            Backend::BruteForce => self.solve_brute_force(),
+            Backend::Clarabel => self.solve_clarabel(),
+            #[cfg(feature = "cbc")]
+            Backend::Cbc => self.solve_cbc(),
+        }
+    }
+
+    /// Solve, then ask `separate` for constraints the solution violates, add them and solve
+    /// again, until there are none. Suits families of constraints that are too large to add up
+    /// front, like subtour elimination.
+    pub fn solve_with_cuts(
+        &mut self,
+        backend: Backend,
+        mut separate: impl FnMut(&Solution) -> Vec<Constraint>,
+    ) -> Result<Solution, OptimError> {
+        loop {
+            let solution = self.solve(backend)?;
+            let cuts = separate(&solution);
+            if cuts.is_empty() {
+                return Ok(solution);
+            }
+            self.constraints.extend(cuts);
+        }
+    }
+
+    /* ------------------------------ brute force ------------------------------ */
+
+    /// Call `f` with every integer point that satisfies the bounds and constraints, ignoring
+    /// the objective. Lets callers brute force objectives that are not linear.
+    pub fn for_each_feasible(&self, mut f: impl FnMut(&[i64])) -> Result<(), OptimError> {
+        if self.vars.iter().any(|def| !def.integer) {
+            return Err(OptimError::Unsupported(
+                "brute force needs integer variables",
+            ));
+        }
+        if self
+            .vars
+            .iter()
+            .any(|def| !def.lower.is_finite() || !def.upper.is_finite())
+        {
+            return Err(OptimError::Unsupported(
+                "brute force needs bounded variables",
+            ));
+        }
+
+        let n = self.len();
+        let rows: Vec<_> = self
+            .constraints
+            .iter()
+            .map(|c| {
+                let (coefficients, constant) = c.expr.dense(n);
+                let (lo, hi) = c.range();
+                // `rest[k]` is the range of `Σ coefficient * var` over the variables `k..n`.
+                let mut rest = vec![(0.0, 0.0); n + 1];
+                for k in (0..n).rev() {
+                    let (a, def) = (coefficients[k], self.vars[k]);
+                    let (x, y) = (a * def.lower, a * def.upper);
+                    rest[k] = (rest[k + 1].0 + x.min(y), rest[k + 1].1 + x.max(y));
+                }
+                Row {
+                    coefficients,
+                    lo: lo - constant,
+                    hi: hi - constant,
+                    rest,
+                }
+            })
+            .collect();
+
+        let mut point = Vec::with_capacity(n);
+        let mut partial = vec![0.0; rows.len()];
+        self.enumerate(&rows, &mut point, &mut partial, &mut f);
+        Ok(())
+    }
+
+    fn enumerate(
+        &self,
+        rows: &[Row],
+        point: &mut Vec<i64>,
+        partial: &mut [f64],
+        f: &mut impl FnMut(&[i64]),
+    ) {
+        let k = point.len();
+        if k == self.len() {
+            if rows
+                .iter()
+                .zip(partial.iter())
+                .all(|(row, &s)| row.holds(s))
+            {
+                f(point);
+            }
+            return;
+        }
+
+        // narrow the bounds of `k` to the values that leave every constraint satisfiable.
+        let def = self.vars[k];
+        let (mut lo, mut hi) = (def.lower, def.upper);
+        for (row, &s) in rows.iter().zip(partial.iter()) {
+            let a = row.coefficients[k];
+            if a == 0.0 {
+                continue;
+            }
+            let (min, max) = (
+                row.lo - s - row.rest[k + 1].1,
+                row.hi - s - row.rest[k + 1].0,
+            );
+            let (min, max) = if a > 0.0 {
+                (min / a, max / a)
+            } else {
+                (max / a, min / a)
+            };
+            lo = lo.max((min - EPS).ceil());
+            hi = hi.min((max + EPS).floor());
+        }
+        if lo > hi {
+            return;
+        }
+
+        for x in lo as i64..=hi as i64 {
+            for (row, s) in rows.iter().zip(partial.iter_mut()) {
+                *s += row.coefficients[k] * x as f64;
+            }
+            point.push(x);
+            self.enumerate(rows, point, partial, f);
+            point.pop();
+            for (row, s) in rows.iter().zip(partial.iter_mut()) {
+                *s -= row.coefficients[k] * x as f64;
+            }
+        }
+    }
+
+    fn solve_brute_force(&self) -> Result<Solution, OptimError> {
+        let mut best: Option<Solution> = None;
+        self.for_each_feasible(|point| {
+            let values: Vec<f64> = point.iter().map(|&x| x as f64).collect();
+            let objective = self.objective.eval(&values);
+            let better = match (self.sense, &best) {
+                (_, None) => true,
+                (Objective::Min, Some(b)) => objective < b.objective,
+                (Objective::Max, Some(b)) => objective > b.objective,
+            };
+            if better {
+                best = Some(Solution { values, objective });
+            }
+        })?;
+        best.ok_or(OptimError::Infeasible)
+    }
+
+    /* -------------------------------- clarabel ------------------------------- */
+
+    /// The continuous relaxation as `min q·x` subject to `Ax + s = b` with `s` in the cones.
+    fn solve_clarabel(&self) -> Result<Solution, OptimError> {
+        let n = self.len();
+        // clarabel wants the rows grouped by cone: equalities first, then inequalities.
+        let (mut equalities, mut inequalities) = (vec![], vec![]);
+        for c in &self.constraints {
+            let (coefficients, constant) = c.expr.dense(n);
+            let rhs = c.rhs - constant;
+            match c.cmp {
+                Cmp::Eq => equalities.push((coefficients, rhs)),
+                Cmp::Le => inequalities.push((coefficients, rhs)),
+                Cmp::Ge => inequalities.push((coefficients.iter().map(|a| -a).collect(), -rhs)),
+            }
+        }
+        for (i, def) in self.vars.iter().enumerate() {
+            let unit = |sign: f64| (0..n).map(|j| if i == j { sign } else { 0.0 }).collect();
+            if def.lower.is_finite() {
+                inequalities.push((unit(-1.0), -def.lower));
+            }
+            if def.upper.is_finite() {
+                inequalities.push((unit(1.0), def.upper));
+            }
+        }
+
+        let mut cones = vec![];
+        if !equalities.is_empty() {
+            cones.push(SupportedConeT::ZeroConeT(equalities.len()));
+        }
+        if !inequalities.is_empty() {
+            cones.push(SupportedConeT::NonnegativeConeT(inequalities.len()));
+        }
+        let rows: Vec<_> = equalities.into_iter().chain(inequalities).collect();
+        let a = DMatrix::from_fn(rows.len(), n, |i, j| rows[i].0[j]);
+        let b: Vec<f64> = rows.iter().map(|(_, rhs)| *rhs).collect();
+
+        let (objective, _) = self.objective.dense(n);
+        let q: Vec<f64> = match self.sense {
+            Objective::Min => objective,
+            Objective::Max => objective.iter().map(|c| -c).collect(),
+        };
+
+        let settings = DefaultSettings {
+            verbose: false,
+            ..DefaultSettings::default()
+        };
+        let p = CscMatrix::zeros((n, n));
+        let mut solver = DefaultSolver::new(&p, &q, &dmatrix_to_csc(&a), &b, &cones, settings)
+            .map_err(|e| OptimError::Solver(e.to_string()))?;
+        solver.solve();
+
+        match solver.solution.status {
+            SolverStatus::Solved | SolverStatus::AlmostSolved => {
+                let values = solver.solution.x.clone();
+                let objective = self.objective.eval(&values);
+                Ok(Solution { values, objective })
+            }
+            SolverStatus::PrimalInfeasible | SolverStatus::AlmostPrimalInfeasible => {
+                Err(OptimError::Infeasible)
+            }
+            SolverStatus::DualInfeasible | SolverStatus::AlmostDualInfeasible => {
+                Err(OptimError::Unbounded)
+            }
+            status => Err(OptimError::Solver(format!("{status:?}"))),
+        }
+    }
+
+    /* ---------------------------------- cbc ---------------------------------- */
+
+    #[cfg(feature = "cbc")]
+    fn solve_cbc(&self) -> Result<Solution, OptimError> {
+        use good_lp::{
+            constraint, solvers::coin_cbc::coin_cbc, variable, Expression, ProblemVariables,
+            ResolutionError, Solution as _, SolverModel,
+        };
+
+        let mut problem = ProblemVariables::new();
+        let vars: Vec<_> = self
+            .vars
+            .iter()
+            .map(|def| {
+                let mut v = variable();
+                if def.integer {
+                    v = v.integer();
+                }
+                if def.lower.is_finite() {
+                    v = v.min(def.lower);
+                }
+                if def.upper.is_finite() {
+                    v = v.max(def.upper);
+                }
+                problem.add(v)
+            })
+            .collect();
+        let to_lp = |expr: &Expr| {
+            let mut lp = Expression::from(expr.constant);
+            for &(v, c) in &expr.terms {
+                lp.add_mul(c, vars[v.0]);
+            }
+            lp
+        };
+
+        let objective = to_lp(&self.objective);
+        let mut model = match self.sense {
+            Objective::Min => problem.minimise(objective),
+            Objective::Max => problem.maximise(objective),
+        }
+        .using(coin_cbc);
+        model.set_parameter("log", "0");
+        for c in &self.constraints {
+            let lhs = to_lp(&c.expr);
+            model.add_constraint(match c.cmp {
+                Cmp::Le => constraint::leq(lhs, c.rhs),
+                Cmp::Ge => constraint::geq(lhs, c.rhs),
+                Cmp::Eq => constraint::eq(lhs, c.rhs),
+            });
+        }
+
+        let solution = model.solve().map_err(|e| match e {
+            ResolutionError::Infeasible => OptimError::Infeasible,
+            ResolutionError::Unbounded => OptimError::Unbounded,
+            e => OptimError::Solver(e.to_string()),
+        })?;
+        let values: Vec<f64> = vars.iter().map(|&v| solution.value(v)).collect();
+        let objective = self.objective.eval(&values);
+        Ok(Solution { values, objective })
+    }
+}
+
+/// A constraint with dense coefficients, as `lo <= Σ coefficient * var <= hi`.
+struct Row {
+    coefficients: Vec<f64>,
+    lo: f64,
+    hi: f64,
+    rest: Vec<(f64, f64)>,
+}
+
+impl Row {
+    fn holds(&self, sum: f64) -> bool {
+        self.lo - EPS <= sum && sum <= self.hi + EPS
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Convert a dense matrix to the compressed sparse column format clarabel takes, dropping zeros.
+pub fn dmatrix_to_csc(dm: &DMatrix<f64>) -> CscMatrix<f64> {
+    let (rows, cols) = dm.shape();
+    let mut colptr = Vec::with_capacity(cols + 1);
+    let mut rowval = vec![];
+    let mut nzval = vec![];
+
+    colptr.push(0);
+    for j in 0..cols {
+        for i in 0..rows {
+            let v = dm[(i, j)];
+            if v != 0.0 {
+                rowval.push(i);
+                nzval.push(v);
+            }
+        }
+        colptr.push(rowval.len());
+    }
+
+    CscMatrix::new(rows, cols, colptr, rowval, nzval)
+}
+
+/// Minimise a smooth convex `problem` from `initial` with argmin's Newton-CG and a Moré-Thuente
+/// line search. Returns the best parameters found within `max_iters` iterations.
+pub fn newton_cg<P>(
+    problem: P,
+    initial: DVector<f64>,
+    max_iters: u64,
+) -> Result<DVector<f64>, OptimError>
+where
+    P: CostFunction<Param = DVector<f64>, Output = f64>
+        + Gradient<Param = DVector<f64>, Gradient = DVector<f64>>
+        + Hessian<Param = DVector<f64>, Hessian = DMatrix<f64>>,
+{
+    let solver = NewtonCG::new(MoreThuenteLineSearch::new());
+    let result = Executor::new(problem, solver)
+        .configure(|state| state.param(initial).max_iters(max_iters))
+        .run()
+        .map_err(|e| OptimError::Solver(e.to_string()))?;
+
+    result
+        .state()
+        .get_best_param()
+        .cloned()
+        .ok_or_else(|| OptimError::Solver("newton-cg found no parameters".to_string()))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use nalgebra::DMatrix;
+
+    use super::{dmatrix_to_csc, Backend, Constraint, Expr, Model, Objective, OptimError};
+
+    /// `max 3x + 2y` subject to `x + y <= 4`, `x + 3y <= 6` and `0 <= x <= 3`, `y >= 0`.
+    /// The relaxation and the integer program share the optimum `(3, 1)`.
+    fn lp() -> Model {
+        let mut model = Model::new(Objective::Max);
+        let (x, y) = (model.integer(0, 3), model.integer(0, 10));
+        model.set_objective(x * 3.0 + y * 2.0);
+        model.add((x + y).at_most(4.0));
+        model.add((x + y * 3.0).at_most(6.0));
+        model
+    }
+
+    #[test]
+    fn backends_agree() {
+        let model = lp();
+        let exact = model.solve(Backend::BruteForce).unwrap();
+        assert_eq!(exact.values, [3.0, 1.0]);
+        assert_eq!(exact.objective, 11.0);
+
+        let relaxed = model.solve(Backend::Clarabel).unwrap();
+        assert!((relaxed.objective - 11.0).abs() < 1e-6);
+        assert!(model.is_feasible(&relaxed.values.iter().map(|v| v.round()).collect::<Vec<_>>()));
+
+        #[cfg(feature = "cbc")]
+        assert_eq!(model.solve(Backend::Cbc).unwrap().values, [3.0, 1.0]);
+    }
+
+    #[test]
+    fn reports_infeasible_models() {
+        let mut model = lp();
+        model.add(Expr::constant(0.0).at_least(1.0));
+        assert_eq!(
+            model.solve(Backend::BruteForce),
+            Err(OptimError::Infeasible)
+        );
+        assert_eq!(model.solve(Backend::Clarabel), Err(OptimError::Infeasible));
+
+        let mut model = Model::new(Objective::Min);
+        model.continuous(0.0, 1.0);
+        assert!(matches!(
+            model.solve(Backend::BruteForce),
+            Err(OptimError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn enumerates_feasible_points() {
+        // compositions of 10 into 3 non-negative parts.
+        let mut model = Model::new(Objective::Min);
+        let vars: Vec<_> = (0..3).map(|_| model.integer(0, 10)).collect();
+        model.add(Expr::sum(vars.iter().copied()).equals(10.0));
+
+        let mut count = 0;
+        model
+            .for_each_feasible(|p| {
+                assert_eq!(p.iter().sum::<i64>(), 10);
+                count += 1;
+            })
+            .unwrap();
+        assert_eq!(count, 66);
+    }
+
+    #[test]
+    fn adds_cuts_until_solutions_are_valid() {
+        // the cheapest round trip through 4 nodes, where `0-1` and `2-3` alone form cheap subtours.
+        let cost = |i: usize, j: usize| if i / 2 == j / 2 { 1.0 } else { 5.0 };
+        let mut model = Model::new(Objective::Min);
+        let next: Vec<Vec<_>> = (0..4)
+            .map(|_| (0..4).map(|_| model.binary()).collect())
+            .collect();
+        let mut objective = Expr::default();
+        for i in 0..4 {
+            for j in 0..4 {
+                objective.add_term(next[i][j], cost(i, j));
+            }
+            model.add(Expr::from(next[i][i]).at_most(0.0));
+            model.add(Expr::sum(next[i].iter().copied()).equals(1.0));
+            model.add(Expr::sum((0..4).map(|j| next[j][i])).equals(1.0));
+        }
+        model.set_objective(objective);
+
+        let mut rounds = 0;
+        let solution = model
+            .solve_with_cuts(Backend::BruteForce, |solution| {
+                rounds += 1;
+                let mut tour = vec![0];
+                loop {
+                    let node = *tour.last().unwrap();
+                    let node = (0..4)
+                        .find(|&j| solution.value(next[node][j]) > 0.5)
+                        .unwrap();
+                    if node == 0 {
+                        break;
+                    }
+                    tour.push(node);
+                }
+                if tour.len() == 4 {
+                    return vec![];
+                }
+                // the nodes of a subtour can use at most `len - 1` edges among each other.
+                let inside = tour.iter().flat_map(|&i| tour.iter().map(move |&j| (i, j)));
+                let cut: Constraint =
+                    Expr::sum(inside.map(|(i, j)| next[i][j])).at_most(tour.len() as f64 - 1.0);
+                vec![cut]
+            })
+            .unwrap();
+
+        assert_eq!(solution.objective, 12.0);
+        assert!(rounds >= 2);
+    }
+
+    #[test]
+    fn converts_to_csc() {
+        let dm = DMatrix::from_row_slice(2, 3, &[1.0, 0.0, 2.0, 0.0, 3.0, 4.0]);
+        let csc = dmatrix_to_csc(&dm);
+        assert_eq!((csc.m, csc.n), (2, 3));
+        assert_eq!(csc.colptr, [0, 1, 2, 4]);
+        assert_eq!(csc.rowval, [0, 1, 0, 1]);
+        assert_eq!(csc.nzval, [1.0, 3.0, 2.0, 4.0]);
+    }
+}
@@ -0,0 +1,259 @@
+//! Helpers to pull integers out of puzzle inputs.
+//!
+//! Integers are extracted from arbitrary text, so `Enter the code at row 2981, column 3075.`
+//! parses as `[2981, 3075]`. For signed types, a `-` directly in front of a number is read as
+//! its sign unless it follows another digit, so ranges like `1-3` still parse as `1, 3`.
+//!
+//! Every function that panics on malformed input has a `try_` variant returning a [`ParseError`].
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// An integer type that can be extracted from text.
+pub trait Integer: FromStr {
+    const SIGNED: bool;
+}
+
+macro_rules! impl_integer {
+    ($signed:expr => $($t:ty),*) => {
+        $( impl Integer for $t { const SIGNED: bool = $signed; } )*
+    };
+}
+
+impl_integer!(false => u8, u16, u32, u64, u128, usize);
+impl_integer!(true => i8, i16, i32, i64, i128, isize);
+
+/// An error which can be returned when parsing integers from an input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// 1-based line of the input, if the error can be attributed to one.
+    pub line: Option<usize>,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    /// The number does not fit the target type, e.g. `-1` or `300` for `u8`.
+    OutOfRange(String),
+    /// A different number of integers than required was found.
+    Arity { expected: usize, found: usize },
+    /// The input does not contain any lines.
+    Empty,
+}
+
+impl ParseError {
+    fn new(kind: ParseErrorKind) -> Self {
+        ParseError { line: None, kind }
+    }
+
+    fn at_line(self, index: usize) -> Self {
+        ParseError {
+            line: Some(index + 1),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {line}: ")?;
+        }
+        match &self.kind {
+            ParseErrorKind::OutOfRange(token) => write!(f, "`{token}` is out of range"),
+            ParseErrorKind::Arity { expected, found } => {
+                write!(f, "expected {expected} integers, found {found}")
+            }
+            ParseErrorKind::Empty => write!(f, "input is empty"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the textual integers in `s`, with a leading `-` if `signed`.
+fn tokens(s: &str, signed: bool) -> impl Iterator<Item = &str> + '_ {
+    let bytes = s.as_bytes();
+    let mut pos = 0;
+
+    std::iter::from_fn(move || {
+        while pos < bytes.len() && !bytes[pos].is_ascii_digit() {
+            pos += 1;
+        }
+        if pos == bytes.len() {
+            return None;
+        }
+
+        let mut start = pos;
+        if signed
+            && start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_digit())
+        {
+            start -= 1;
+        }
+
+        while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+            pos += 1;
+        }
+
+        Some(&s[start..pos])
+    })
+}
+
+fn parse_token<T: Integer>(token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(ParseErrorKind::OutOfRange(token.to_string())))
+}
+
+fn collect_array<T: Integer, const N: usize>(s: &str) -> Result<[T; N], ParseError> {
+    let values = try_integers(s).collect::<Result<Vec<T>, _>>()?;
+    let found = values.len();
+    values
+        .try_into()
+        .map_err(|_| ParseError::new(ParseErrorKind::Arity { expected: N, found }))
+}
+
+fn unwrap<T>(result: Result<T, ParseError>) -> T {
+    result.unwrap_or_else(|e| panic!("failed to parse input: {e}"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Extract all integers from `s`.
+pub fn try_integers<'a, T: Integer + 'a>(
+    s: &'a str,
+) -> impl Iterator<Item = Result<T, ParseError>> + 'a {
+    tokens(s, T::SIGNED).map(parse_token)
+}
+
+/// Extract all integers from `s`.
+///
+/// # Panics
+/// If an integer does not fit into `T`.
+pub fn integers<'a, T: Integer + 'a>(s: &'a str) -> impl Iterator<Item = T> + 'a {
+    try_integers(s).map(unwrap)
+}
+
+/// Extract exactly `N` integers from `s`.
+pub fn try_array<T: Integer, const N: usize>(s: &str) -> Result<[T; N], ParseError> {
+    collect_array(s)
+}
+
+/// Extract exactly `N` integers from `s`.
+///
+/// # Panics
+/// If `s` does not contain exactly `N` integers that fit into `T`.
+pub fn array<T: Integer, const N: usize>(s: &str) -> [T; N] {
+    unwrap(collect_array(s))
+}
+
+/// Extract exactly `N` integers from the first line of `s`.
+pub fn try_parse_first_line<T: Integer, const N: usize>(s: &str) -> Result<[T; N], ParseError> {
+    let line = s
+        .lines()
+        .next()
+        .ok_or(ParseError::new(ParseErrorKind::Empty))?;
+    collect_array(line).map_err(|e| e.at_line(0))
+}
+
+/// Extract exactly `N` integers from the first line of `s`.
+///
+/// # Panics
+/// If the first line does not contain exactly `N` integers that fit into `T`.
+pub fn parse_first_line<T: Integer, const N: usize>(s: &str) -> [T; N] {
+    unwrap(try_parse_first_line(s))
+}
+
+/// Extract the integers of each line of `s`.
+///
+/// # Panics
+/// When advancing past an integer that does not fit into `T`.
+pub fn lines_parsed<'a, T: Integer + 'a>(
+    s: &'a str,
+) -> impl Iterator<Item = impl Iterator<Item = T> + 'a> {
+    s.lines().map(integers)
+}
+
+/// Extract exactly `N` integers from each line of `s`, skipping empty lines.
+pub fn try_lines_array<T: Integer, const N: usize>(s: &str) -> Result<Vec<[T; N]>, ParseError> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| collect_array(line).map_err(|e| e.at_line(i)))
+        .collect()
+}
+
+/// Extract exactly `N` integers from each line of `s`, skipping empty lines.
+///
+/// # Panics
+/// If a line does not contain exactly `N` integers that fit into `T`.
+pub fn lines_array<T: Integer, const N: usize>(s: &str) -> Vec<[T; N]> {
+    unwrap(try_lines_array(s))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        array, integers, lines_array, lines_parsed, parse_first_line, try_array, try_integers,
+        try_lines_array, try_parse_first_line, ParseError, ParseErrorKind,
+    };
+
+    #[test]
+    fn extracts_integers_from_text() {
+        let input = "To continue, enter the code at row 2981, column 3075.";
+        assert_eq!(integers::<u64>(input).collect::<Vec<_>>(), [2981, 3075]);
+        assert_eq!(parse_first_line::<usize, 2>(input), [2981, 3075]);
+    }
+
+    #[test]
+    fn reads_signs_for_signed_types() {
+        let input = "x=-5, y=12..-3, 1-3";
+        assert_eq!(
+            integers::<i32>(input).collect::<Vec<_>>(),
+            [-5, 12, -3, 1, 3]
+        );
+        assert_eq!(integers::<u32>(input).collect::<Vec<_>>(), [5, 12, 3, 1, 3]);
+    }
+
+    #[test]
+    fn parses_lines() {
+        let input = "1 2\n\n3 4\n";
+        let lines: Vec<Vec<u8>> = lines_parsed(input).map(Iterator::collect).collect();
+        assert_eq!(lines, [vec![1, 2], vec![], vec![3, 4]]);
+        assert_eq!(lines_array::<u8, 2>(input), [[1, 2], [3, 4]]);
+        assert_eq!(array::<i8, 3>("1, -2, 3"), [1, -2, 3]);
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert_eq!(
+            try_lines_array::<u8, 2>("1 2\n3 4 5").unwrap_err(),
+            ParseError {
+                line: Some(2),
+                kind: ParseErrorKind::Arity {
+                    expected: 2,
+                    found: 3
+                }
+            }
+        );
+        assert_eq!(
+            try_array::<u8, 1>("300").unwrap_err().to_string(),
+            "`300` is out of range"
+        );
+        assert_eq!(
+            try_parse_first_line::<u8, 1>("").unwrap_err().kind,
+            ParseErrorKind::Empty
+        );
+        assert!(try_integers::<u8>("1 256").any(|x| x.is_err()));
+    }
+
+    #[test]
+    #[should_panic(expected = "line 1: expected 2 integers, found 1")]
+    fn panics_on_wrong_arity() {
+        parse_first_line::<u32, 2>("42");
+    }
+}
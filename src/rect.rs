@@ -0,0 +1,338 @@
+//! Axis-aligned rectangles on the integer plane, for puzzles that paint regions.
+//!
+//! [`RectSet`] keeps the area covered by a set of rectangles as disjoint pieces and supports
+//! union, difference and symmetric difference. [`Regions`] compresses the coordinates of a
+//! known list of rectangles into a grid of regions, each carrying a value, so per-cell counters
+//! only cost one update per region instead of one per cell.
+use std::ops::Range;
+
+use crate::grid::Grid;
+
+/// The cells `x0..x1` × `y0..y1`. Rectangles with `x1 <= x0` or `y1 <= y0` are empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub x0: i64,
+    pub y0: i64,
+    pub x1: i64,
+    pub y1: i64,
+}
+
+impl Rect {
+    pub fn new(x0: i64, y0: i64, x1: i64, y1: i64) -> Self {
+        Rect { x0, y0, x1, y1 }
+    }
+
+    /// The cells `x0..=x1` × `y0..=y1`, as puzzles usually give them.
+    pub fn inclusive(x0: i64, y0: i64, x1: i64, y1: i64) -> Self {
+        Rect::new(x0, y0, x1 + 1, y1 + 1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.x1 <= self.x0 || self.y1 <= self.y0
+    }
+
+    /// The number of cells.
+    pub fn area(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            (self.x1 - self.x0) as u64 * (self.y1 - self.y0) as u64
+        }
+    }
+
+    pub fn contains(&self, (x, y): (i64, i64)) -> bool {
+        (self.x0..self.x1).contains(&x) && (self.y0..self.y1).contains(&y)
+    }
+
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let rect = Rect::new(
+            self.x0.max(other.x0),
+            self.y0.max(other.y0),
+            self.x1.min(other.x1),
+            self.y1.min(other.y1),
+        );
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    /// The cells of `self` outside `other`, as up to four disjoint rectangles.
+    pub fn subtract(&self, other: &Rect) -> Vec<Rect> {
+        let Some(cut) = self.intersection(other) else {
+            return if self.is_empty() { vec![] } else { vec![*self] };
+        };
+
+        [
+            Rect::new(self.x0, self.y0, self.x1, cut.y0),
+            Rect::new(self.x0, cut.y1, self.x1, self.y1),
+            Rect::new(self.x0, cut.y0, cut.x0, cut.y1),
+            Rect::new(cut.x1, cut.y0, self.x1, cut.y1),
+        ]
+        .into_iter()
+        .filter(|r| !r.is_empty())
+        .collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The area covered by any number of rectangles, stored as disjoint rectangles.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RectSet {
+    rects: Vec<Rect>,
+}
+
+impl RectSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The disjoint pieces of the set, in no particular order.
+    pub fn rects(&self) -> &[Rect] {
+        &self.rects
+    }
+
+    /// The number of cells in the set.
+    pub fn area(&self) -> u64 {
+        self.rects.iter().map(Rect::area).sum()
+    }
+
+    pub fn contains(&self, point: (i64, i64)) -> bool {
+        self.rects.iter().any(|r| r.contains(point))
+    }
+
+    /// The pieces of `rect` that are not in the set yet.
+    fn uncovered(&self, rect: Rect) -> Vec<Rect> {
+        self.rects.iter().fold(vec![rect], |pieces, existing| {
+            pieces.iter().flat_map(|p| p.subtract(existing)).collect()
+        })
+    }
+
+    /// Add the cells of `rect` (union).
+    pub fn insert(&mut self, rect: Rect) {
+        let pieces = self.uncovered(rect);
+        self.rects.extend(pieces);
+    }
+
+    /// Remove the cells of `rect` (difference).
+    pub fn remove(&mut self, rect: Rect) {
+        self.rects = self.rects.iter().flat_map(|r| r.subtract(&rect)).collect();
+    }
+
+    /// Flip the cells of `rect`, adding those not in the set and removing the others
+    /// (symmetric difference).
+    pub fn toggle(&mut self, rect: Rect) {
+        let added = self.uncovered(rect);
+        self.remove(rect);
+        self.rects.extend(added);
+    }
+
+    pub fn union(&self, other: &RectSet) -> RectSet {
+        let mut set = self.clone();
+        for &rect in &other.rects {
+            set.insert(rect);
+        }
+        set
+    }
+
+    pub fn difference(&self, other: &RectSet) -> RectSet {
+        let mut set = self.clone();
+        for &rect in &other.rects {
+            set.remove(rect);
+        }
+        set
+    }
+
+    pub fn symmetric_difference(&self, other: &RectSet) -> RectSet {
+        let mut set = self.clone();
+        // the pieces of `other` are disjoint, so toggling them one by one is independent.
+        for &rect in &other.rects {
+            set.toggle(rect);
+        }
+        set
+    }
+}
+
+impl FromIterator<Rect> for RectSet {
+    fn from_iter<I: IntoIterator<Item = Rect>>(iter: I) -> Self {
+        let mut set = RectSet::new();
+        for rect in iter {
+            set.insert(rect);
+        }
+        set
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A value for every region of the plane cut out by the edges of a list of rectangles.
+/// Cells outside all of the rectangles are not tracked.
+#[derive(Debug, Clone)]
+pub struct Regions<T> {
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+    values: Grid<T>,
+}
+
+impl<T: Clone> Regions<T> {
+    /// Compress the coordinates of `rects`, starting every region at `value`.
+    pub fn new<'a>(rects: impl IntoIterator<Item = &'a Rect>, value: T) -> Self {
+        let (mut xs, mut ys) = (vec![], vec![]);
+        for rect in rects.into_iter().filter(|r| !r.is_empty()) {
+            xs.extend([rect.x0, rect.x1]);
+            ys.extend([rect.y0, rect.y1]);
+        }
+        for coords in [&mut xs, &mut ys] {
+            coords.sort_unstable();
+            coords.dedup();
+        }
+
+        let values = Grid::new(
+            ys.len().saturating_sub(1),
+            xs.len().saturating_sub(1),
+            value,
+        );
+        Regions { xs, ys, values }
+    }
+}
+
+impl<T> Regions<T> {
+    /// The number of regions.
+    pub fn len(&self) -> usize {
+        self.values.rows() * self.values.cols()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn span(coords: &[i64], lo: i64, hi: i64) -> Range<usize> {
+        let index = |c: i64| {
+            coords
+                .binary_search(&c)
+                .unwrap_or_else(|_| panic!("{c} is not an edge of the compressed rectangles"))
+        };
+        index(lo)..index(hi)
+    }
+
+    /// Apply `f` to the value of every region inside `rect`.
+    ///
+    /// # Panics
+    /// If `rect` is not one of the rectangles the regions were built from (or at least does not
+    /// lie on their edges).
+    pub fn update(&mut self, rect: &Rect, mut f: impl FnMut(&mut T)) {
+        if rect.is_empty() {
+            return;
+        }
+        let rows = Self::span(&self.ys, rect.y0, rect.y1);
+        let cols = Self::span(&self.xs, rect.x0, rect.x1);
+        for row in self.values.rect_mut(rows, cols) {
+            row.iter_mut().for_each(&mut f);
+        }
+    }
+
+    /// Every region with its value.
+    pub fn iter(&self) -> impl Iterator<Item = (Rect, &T)> {
+        self.values.enumerate().map(|((r, c), value)| {
+            let rect = Rect::new(self.xs[c], self.ys[r], self.xs[c + 1], self.ys[r + 1]);
+            (rect, value)
+        })
+    }
+
+    /// The sum of `f(value)` over every cell.
+    pub fn sum(&self, f: impl Fn(&T) -> u64) -> u64 {
+        self.iter()
+            .map(|(rect, value)| rect.area() * f(value))
+            .sum()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::{Rect, RectSet, Regions};
+
+    const SIZE: i64 = 20;
+
+    fn random_rect(rng: &mut StdRng) -> Rect {
+        let (x0, y0) = (rng.random_range(0..SIZE), rng.random_range(0..SIZE));
+        let (x1, y1) = (rng.random_range(x0..SIZE), rng.random_range(y0..SIZE));
+        Rect::inclusive(x0, y0, x1, y1)
+    }
+
+    fn cells(rect: &Rect) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (rect.y0..rect.y1)
+            .flat_map(move |y| (rect.x0..rect.x1).map(move |x| (y as usize, x as usize)))
+    }
+
+    #[test]
+    fn subtracts_rects() {
+        let outer = Rect::new(0, 0, 4, 4);
+        let pieces = outer.subtract(&Rect::new(1, 1, 3, 3));
+        assert_eq!(pieces.len(), 4);
+        assert_eq!(pieces.iter().map(Rect::area).sum::<u64>(), 12);
+        assert_eq!(outer.subtract(&Rect::new(5, 5, 6, 6)), [outer]);
+        assert!(outer.subtract(&Rect::new(-1, -1, 5, 5)).is_empty());
+    }
+
+    #[test]
+    fn set_operations_match_brute_force() {
+        let mut rng = StdRng::seed_from_u64(2015);
+        for _ in 0..50 {
+            let mut set = RectSet::new();
+            let mut grid = [[false; SIZE as usize]; SIZE as usize];
+
+            for _ in 0..20 {
+                let rect = random_rect(&mut rng);
+                match rng.random_range(0..3) {
+                    0 => {
+                        set.insert(rect);
+                        cells(&rect).for_each(|(y, x)| grid[y][x] = true);
+                    }
+                    1 => {
+                        set.remove(rect);
+                        cells(&rect).for_each(|(y, x)| grid[y][x] = false);
+                    }
+                    _ => {
+                        set.toggle(rect);
+                        cells(&rect).for_each(|(y, x)| grid[y][x] = !grid[y][x]);
+                    }
+                }
+            }
+
+            let lit = grid.iter().flatten().filter(|&&b| b).count() as u64;
+            assert_eq!(set.area(), lit);
+            assert!(cells(&Rect::new(0, 0, SIZE, SIZE))
+                .all(|(y, x)| set.contains((x as i64, y as i64)) == grid[y][x]));
+        }
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a: RectSet = [Rect::new(0, 0, 4, 4)].into_iter().collect();
+        let b: RectSet = [Rect::new(2, 2, 6, 6)].into_iter().collect();
+        assert_eq!(a.union(&b).area(), 28);
+        assert_eq!(a.difference(&b).area(), 12);
+        assert_eq!(a.symmetric_difference(&b).area(), 24);
+    }
+
+    #[test]
+    fn counts_regions() {
+        let mut rng = StdRng::seed_from_u64(2015);
+        let rects: Vec<Rect> = (0..30).map(|_| random_rect(&mut rng)).collect();
+
+        let mut regions = Regions::new(&rects, 0u64);
+        let mut grid = [[0u64; SIZE as usize]; SIZE as usize];
+        for (i, rect) in rects.iter().enumerate() {
+            regions.update(rect, |v| *v += i as u64);
+            cells(rect).for_each(|(y, x)| grid[y][x] += i as u64);
+        }
+
+        assert_eq!(regions.sum(|&v| v), grid.iter().flatten().sum::<u64>());
+        assert_eq!(
+            regions.sum(|&v| u64::from(v > 100)),
+            grid.iter().flatten().filter(|&&v| v > 100).count() as u64
+        );
+    }
+}
@@ -0,0 +1,421 @@
+//! Extract typed fields from lines that follow a fixed sentence structure.
+//!
+//! A pattern is literal text with `{name}` placeholders, e.g.
+//! `"{name} can fly {v} km/s for {l} seconds, but then must rest for {r} seconds."`.
+//! Each placeholder captures the text up to the next occurrence of the literal that follows it,
+//! or the rest of the line if it is the last part. Use `{{` and `}}` for literal braces.
+//!
+//! ```ignore
+//! let (name, v, l, r): (&str, u64, u64, u64) = scan!(
+//!     line,
+//!     "{name} can fly {v} km/s for {l} seconds, but then must rest for {r} seconds."
+//! )?;
+//! ```
+use std::{any::type_name, error::Error, fmt::Display};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Part {
+    Literal(String),
+    Field(String),
+}
+
+/// A compiled line pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    parts: Vec<Part>,
+}
+
+/// An error which can be returned when a line does not match a [`Pattern`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanError {
+    /// 1-based line of the input, if known.
+    pub line: Option<usize>,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub kind: ScanErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScanErrorKind {
+    /// The literal text of the pattern was not found.
+    Expected(String),
+    /// The captured text could not be converted to the type of the field.
+    Invalid {
+        field: String,
+        value: String,
+        ty: &'static str,
+    },
+    /// The line continues after the end of the pattern.
+    TrailingInput(String),
+    /// None of the alternatives a caller accepts at this point was found, e.g. the keywords
+    /// a line can start with.
+    ExpectedOneOf(&'static [&'static str]),
+}
+
+impl ScanError {
+    /// An error at a 1-based column of a line, for checks done outside a pattern.
+    pub fn new(column: usize, kind: ScanErrorKind) -> Self {
+        ScanError {
+            line: None,
+            column,
+            kind,
+        }
+    }
+
+    /// Shift the column by `offset` characters, for errors of a pattern matched against the
+    /// rest of a line.
+    #[must_use]
+    pub fn offset(self, offset: usize) -> Self {
+        ScanError {
+            column: self.column + offset,
+            ..self
+        }
+    }
+
+    /// Attach the 0-based index of the line the error occurred on.
+    #[must_use]
+    pub fn at_line(self, index: usize) -> Self {
+        ScanError {
+            line: Some(index + 1),
+            ..self
+        }
+    }
+}
+
+impl Display for ScanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {line}, ")?;
+        }
+        write!(f, "column {}: ", self.column)?;
+        match &self.kind {
+            ScanErrorKind::Expected(literal) => write!(f, "expected `{literal}`"),
+            ScanErrorKind::Invalid { field, value, ty } => {
+                write!(f, "`{value}` is not a valid {ty} for field `{field}`")
+            }
+            ScanErrorKind::TrailingInput(rest) => {
+                write!(f, "unexpected `{rest}` after the pattern")
+            }
+            ScanErrorKind::ExpectedOneOf(options) => {
+                let options: Vec<String> = options.iter().map(|o| format!("`{o}`")).collect();
+                write!(f, "expected one of {}", options.join(", "))
+            }
+        }
+    }
+}
+
+impl Error for ScanError {}
+
+/* -------------------------------------------------------------------------- */
+
+/// A type that a single field can be converted to.
+pub trait FromField<'a>: Sized {
+    fn from_field(s: &'a str) -> Option<Self>;
+}
+
+impl<'a> FromField<'a> for &'a str {
+    fn from_field(s: &'a str) -> Option<Self> {
+        Some(s)
+    }
+}
+
+impl FromField<'_> for String {
+    fn from_field(s: &str) -> Option<Self> {
+        Some(s.to_string())
+    }
+}
+
+impl FromField<'_> for char {
+    fn from_field(s: &str) -> Option<Self> {
+        let mut chars = s.chars();
+        chars.next().filter(|_| chars.next().is_none())
+    }
+}
+
+macro_rules! impl_from_field {
+    ($($t:ty),*) => {
+        $( impl FromField<'_> for $t {
+            fn from_field(s: &str) -> Option<Self> {
+                s.parse().ok()
+            }
+        } )*
+    };
+}
+
+impl_from_field!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool);
+
+/// The values of all fields of a pattern, i.e. a single [`FromField`] or a tuple of them.
+pub trait FromFields<'a>: Sized {
+    const COUNT: usize;
+
+    /// On failure, returns the index of the field that could not be converted and its type.
+    fn from_fields(values: &[&'a str]) -> Result<Self, (usize, &'static str)>;
+}
+
+impl<'a, T: FromField<'a>> FromFields<'a> for T {
+    const COUNT: usize = 1;
+
+    fn from_fields(values: &[&'a str]) -> Result<Self, (usize, &'static str)> {
+        T::from_field(values[0]).ok_or((0, type_name::<T>()))
+    }
+}
+
+macro_rules! impl_from_fields {
+    ($count:expr => $($t:ident $i:tt),+) => {
+        impl<'a, $($t: FromField<'a>),+> FromFields<'a> for ($($t,)+) {
+            const COUNT: usize = $count;
+
+            fn from_fields(values: &[&'a str]) -> Result<Self, (usize, &'static str)> {
+                Ok(($( $t::from_field(values[$i]).ok_or(($i, type_name::<$t>()))?, )+))
+            }
+        }
+    };
+}
+
+impl_from_fields!(2 => A 0, B 1);
+impl_from_fields!(3 => A 0, B 1, C 2);
+impl_from_fields!(4 => A 0, B 1, C 2, D 3);
+impl_from_fields!(5 => A 0, B 1, C 2, D 3, E 4);
+impl_from_fields!(6 => A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_fields!(7 => A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_fields!(8 => A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/* -------------------------------------------------------------------------- */
+
+impl Pattern {
+    /// Compile a pattern.
+    ///
+    /// # Panics
+    /// If a brace is not closed, or two fields are not separated by literal text.
+    pub fn new(pattern: &str) -> Self {
+        let mut parts = vec![];
+        let mut literal = String::new();
+        let mut chars = pattern.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('{', Some('{')) | ('}', Some('}')) => {
+                    chars.next();
+                    literal.push(c);
+                }
+                ('{', _) => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => name.push(c),
+                            None => panic!("invalid pattern `{pattern}`: unclosed `{{`"),
+                        }
+                    }
+                    if !literal.is_empty() {
+                        parts.push(Part::Literal(std::mem::take(&mut literal)));
+                    }
+                    assert!(
+                        !matches!(parts.last(), Some(Part::Field(_))),
+                        "invalid pattern `{pattern}`: fields must be separated by literal text"
+                    );
+                    parts.push(Part::Field(name));
+                }
+                ('}', _) => panic!("invalid pattern `{pattern}`: unmatched `}}`"),
+                _ => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+
+        Pattern { parts }
+    }
+
+    fn field_names(&self) -> impl Iterator<Item = &str> {
+        self.parts.iter().filter_map(|p| match p {
+            Part::Field(name) => Some(name.as_str()),
+            Part::Literal(_) => None,
+        })
+    }
+
+    /// Returns the byte offset and text of each field.
+    fn captures<'a>(&self, line: &'a str) -> Result<Vec<(usize, &'a str)>, ScanError> {
+        let column = |pos: usize| line[..pos].chars().count() + 1;
+        let error = |pos: usize, kind| ScanError {
+            line: None,
+            column: column(pos),
+            kind,
+        };
+
+        let mut captures = vec![];
+        let mut pos = 0;
+
+        for (i, part) in self.parts.iter().enumerate() {
+            match part {
+                Part::Literal(literal) => {
+                    if !line[pos..].starts_with(literal.as_str()) {
+                        return Err(error(pos, ScanErrorKind::Expected(literal.clone())));
+                    }
+                    pos += literal.len();
+                }
+                Part::Field(_) => {
+                    let end = match self.parts.get(i + 1) {
+                        Some(Part::Literal(next)) => line[pos..]
+                            .find(next.as_str())
+                            .map(|x| pos + x)
+                            .ok_or_else(|| error(pos, ScanErrorKind::Expected(next.clone())))?,
+                        _ => line.len(),
+                    };
+                    captures.push((pos, &line[pos..end]));
+                    pos = end;
+                }
+            }
+        }
+
+        if pos < line.len() {
+            return Err(error(
+                pos,
+                ScanErrorKind::TrailingInput(line[pos..].to_string()),
+            ));
+        }
+
+        Ok(captures)
+    }
+
+    /// Match a single line and convert its fields.
+    ///
+    /// # Panics
+    /// If `T` does not have as many values as the pattern has fields.
+    pub fn scan<'a, T: FromFields<'a>>(&self, line: &'a str) -> Result<T, ScanError> {
+        let captures = self.captures(line)?;
+        assert_eq!(
+            captures.len(),
+            T::COUNT,
+            "pattern has {} fields, but {} values were requested",
+            captures.len(),
+            T::COUNT
+        );
+
+        let values: Vec<&str> = captures.iter().map(|(_, value)| *value).collect();
+        T::from_fields(&values).map_err(|(i, ty)| ScanError {
+            line: None,
+            column: line[..captures[i].0].chars().count() + 1,
+            kind: ScanErrorKind::Invalid {
+                field: self.field_names().nth(i).unwrap_or_default().to_string(),
+                value: values[i].to_string(),
+                ty,
+            },
+        })
+    }
+
+    /// Match every non-empty line of `input`. Errors carry the line number.
+    pub fn scan_lines<'a, T: FromFields<'a>>(&self, input: &'a str) -> Result<Vec<T>, ScanError> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| self.scan(line).map_err(|e| e.at_line(i)))
+            .collect()
+    }
+}
+
+/// Match a line against a pattern literal and extract its fields, see [`scan`](crate::scan).
+/// The pattern is compiled once per call site.
+///
+/// `scan!(line, "pattern")` matches a single line, `scan!(lines input, "pattern")` all non-empty lines.
+#[macro_export]
+macro_rules! scan {
+    (lines $input:expr, $pattern:literal) => {
+        $crate::scan!(@pattern $pattern).scan_lines($input)
+    };
+    ($line:expr, $pattern:literal) => {
+        $crate::scan!(@pattern $pattern).scan($line)
+    };
+    (@pattern $pattern:literal) => {{
+        static PATTERN: ::std::sync::OnceLock<$crate::scan::Pattern> = ::std::sync::OnceLock::new();
+        PATTERN.get_or_init(|| $crate::scan::Pattern::new($pattern))
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Pattern, ScanError, ScanErrorKind};
+
+    const REINDEER: &str =
+        "{name} can fly {v} km/s for {l} seconds, but then must rest for {r} seconds.";
+
+    #[test]
+    fn extracts_typed_fields() {
+        let line = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.";
+        let (name, v, l, r): (&str, u64, u8, u32) = crate::scan!(
+            line,
+            "{name} can fly {v} km/s for {l} seconds, but then must rest for {r} seconds."
+        )
+        .unwrap();
+        assert_eq!((name, v, l, r), ("Comet", 14, 10, 127));
+    }
+
+    #[test]
+    fn extracts_single_fields() {
+        let n: i32 = crate::scan!("gain 54", "gain {n}").unwrap();
+        assert_eq!(n, 54);
+        let braces: char = Pattern::new("{{{c}}}").scan("{x}").unwrap();
+        assert_eq!(braces, 'x');
+    }
+
+    #[test]
+    fn scans_lines() {
+        let input = "London to Dublin = 464\n\nLondon to Belfast = 518\n";
+        let routes: Vec<(&str, &str, u64)> =
+            crate::scan!(lines input, "{from} to {to} = {distance}").unwrap();
+        assert_eq!(
+            routes,
+            [("London", "Dublin", 464), ("London", "Belfast", 518)]
+        );
+    }
+
+    #[test]
+    fn reports_positions() {
+        let pattern = Pattern::new(REINDEER);
+        let err = pattern
+            .scan::<(&str, u64, u64, u64)>("Dancer can fly 27 m/s for 5 seconds")
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ScanError {
+                line: None,
+                column: 16,
+                kind: ScanErrorKind::Expected(" km/s for ".into())
+            }
+        );
+
+        let err = pattern
+            .scan_lines::<(&str, u64, u64, u64)>(
+                "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.\n\
+                Vixen can fly x km/s for 8 seconds, but then must rest for 53 seconds.",
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 15: `x` is not a valid u64 for field `v`"
+        );
+
+        let err = Pattern::new("{a} -> {b}")
+            .scan::<(&str, &str)>("x")
+            .unwrap_err();
+        assert_eq!(err.to_string(), "column 1: expected ` -> `");
+
+        let err = ScanError::new(3, ScanErrorKind::ExpectedOneOf(&["AND", "OR"]))
+            .offset(2)
+            .at_line(0);
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 5: expected one of `AND`, `OR`"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "fields must be separated")]
+    fn rejects_adjacent_fields() {
+        Pattern::new("{a}{b}");
+    }
+}
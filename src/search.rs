@@ -0,0 +1,355 @@
+//! Searches over implicit state spaces.
+//!
+//! Implement [`State`] for a puzzle state, then call [`bfs`], [`dijkstra`], [`astar`] or
+//! [`branch_and_bound`] with the start state. Each returns the optimal path, if a goal is
+//! reachable, together with [`Stats`] about the work done.
+//!
+//! States with equal [`State::key`] are treated as the same state, so the key must capture
+//! everything that affects the remaining search (but not e.g. the cost spent so far).
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    fmt::Debug,
+    hash::Hash,
+    ops::Add,
+};
+
+pub trait State: Clone {
+    type Key: Hash + Eq;
+    type Cost: Copy + Ord + Default + Debug + Add<Output = Self::Cost>;
+
+    /// The states reachable in one step, with the cost of that step.
+    fn successors(&self) -> Vec<(Self, Self::Cost)>;
+
+    fn is_goal(&self) -> bool;
+
+    fn key(&self) -> Self::Key;
+
+    /// A lower bound of the cost from this state to a goal, used by [`astar`] and
+    /// [`branch_and_bound`]. It must never overestimate, or the result may not be optimal.
+    fn heuristic(&self) -> Self::Cost {
+        Self::Cost::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// States whose successors were generated.
+    pub expanded: usize,
+    /// Successors generated in total.
+    pub generated: usize,
+    /// Successors discarded as duplicates or by a bound.
+    pub pruned: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Path<S: State> {
+    pub cost: S::Cost,
+    /// From the start state to the goal, inclusive.
+    pub states: Vec<S>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Outcome<S: State> {
+    /// `None` if no goal is reachable.
+    pub path: Option<Path<S>>,
+    pub stats: Stats,
+}
+
+impl<S: State> Outcome<S> {
+    pub fn cost(&self) -> Option<S::Cost> {
+        self.path.as_ref().map(|p| p.cost)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Every state reached so far with the node it was reached from, to recover paths.
+struct Tree<S: State> {
+    nodes: Vec<(S, Option<usize>, S::Cost)>,
+}
+
+impl<S: State> Tree<S> {
+    fn new(start: S) -> Self {
+        Tree {
+            nodes: vec![(start, None, S::Cost::default())],
+        }
+    }
+
+    fn push(&mut self, state: S, parent: usize, cost: S::Cost) -> usize {
+        self.nodes.push((state, Some(parent), cost));
+        self.nodes.len() - 1
+    }
+
+    fn cost(&self, node: usize) -> S::Cost {
+        self.nodes[node].2
+    }
+
+    fn path(&self, node: usize) -> Path<S> {
+        let mut states = vec![];
+        let mut current = Some(node);
+        while let Some(i) = current {
+            states.push(self.nodes[i].0.clone());
+            current = self.nodes[i].1;
+        }
+        states.reverse();
+        Path {
+            cost: self.cost(node),
+            states,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Breadth-first search, finding the path with the fewest steps regardless of their cost.
+pub fn bfs<S: State>(start: S) -> Outcome<S> {
+    let mut stats = Stats::default();
+    let mut seen = HashSet::from([start.key()]);
+    let mut tree = Tree::new(start);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(i) = queue.pop_front() {
+        if tree.nodes[i].0.is_goal() {
+            return Outcome {
+                path: Some(tree.path(i)),
+                stats,
+            };
+        }
+
+        stats.expanded += 1;
+        for (next, step) in tree.nodes[i].0.successors() {
+            stats.generated += 1;
+            if !seen.insert(next.key()) {
+                stats.pruned += 1;
+                continue;
+            }
+            let cost = tree.cost(i) + step;
+            queue.push_back(tree.push(next, i, cost));
+        }
+    }
+
+    Outcome { path: None, stats }
+}
+
+/// Dijkstra's algorithm, finding the cheapest path.
+pub fn dijkstra<S: State>(start: S) -> Outcome<S> {
+    best_first(start, false)
+}
+
+/// A* search, finding the cheapest path while expanding states in order of
+/// cost plus [`State::heuristic`].
+pub fn astar<S: State>(start: S) -> Outcome<S> {
+    best_first(start, true)
+}
+
+fn best_first<S: State>(start: S, use_heuristic: bool) -> Outcome<S> {
+    let estimate = |state: &S, cost: S::Cost| {
+        if use_heuristic {
+            cost + state.heuristic()
+        } else {
+            cost
+        }
+    };
+
+    let mut stats = Stats::default();
+    let mut best = HashMap::from([(start.key(), S::Cost::default())]);
+    let mut heap = BinaryHeap::from([Reverse((estimate(&start, S::Cost::default()), 0))]);
+    let mut tree = Tree::new(start);
+
+    while let Some(Reverse((_, i))) = heap.pop() {
+        let (state, cost) = (&tree.nodes[i].0, tree.cost(i));
+        // a cheaper way to this state was found after it was queued.
+        if best.get(&state.key()).is_some_and(|&b| b < cost) {
+            continue;
+        }
+        if state.is_goal() {
+            return Outcome {
+                path: Some(tree.path(i)),
+                stats,
+            };
+        }
+
+        stats.expanded += 1;
+        for (next, step) in state.successors() {
+            stats.generated += 1;
+            let cost = cost + step;
+            let key = next.key();
+            if best.get(&key).is_some_and(|&b| b <= cost) {
+                stats.pruned += 1;
+                continue;
+            }
+            best.insert(key, cost);
+            let priority = estimate(&next, cost);
+            heap.push(Reverse((priority, tree.push(next, i, cost))));
+        }
+    }
+
+    Outcome { path: None, stats }
+}
+
+/// Depth-first branch and bound, finding the cheapest path. Once a goal is found, states whose
+/// cost plus [`State::heuristic`] cannot beat it are pruned. Uses little memory for the
+/// frontier, which suits deep searches with a good heuristic.
+pub fn branch_and_bound<S: State>(start: S) -> Outcome<S> {
+    let mut stats = Stats::default();
+    let mut incumbent: Option<(S::Cost, usize)> = None;
+    let mut best = HashMap::from([(start.key(), S::Cost::default())]);
+    let mut tree = Tree::new(start);
+    let mut stack = vec![0];
+
+    let bounded = |incumbent: Option<(S::Cost, usize)>, state: &S, cost: S::Cost| {
+        incumbent.is_some_and(|(b, _)| cost + state.heuristic() >= b)
+    };
+
+    while let Some(i) = stack.pop() {
+        let (state, cost) = (&tree.nodes[i].0, tree.cost(i));
+        // the bound may have tightened since this state was pushed.
+        if bounded(incumbent, state, cost) {
+            stats.pruned += 1;
+            continue;
+        }
+        if state.is_goal() {
+            incumbent = Some((cost, i));
+            continue;
+        }
+
+        stats.expanded += 1;
+        for (next, step) in state.successors() {
+            stats.generated += 1;
+            let cost = cost + step;
+            let key = next.key();
+            if bounded(incumbent, &next, cost) || best.get(&key).is_some_and(|&b| b <= cost) {
+                stats.pruned += 1;
+                continue;
+            }
+            best.insert(key, cost);
+            stack.push(tree.push(next, i, cost));
+        }
+    }
+
+    Outcome {
+        path: incumbent.map(|(_, i)| tree.path(i)),
+        stats,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, branch_and_bound, dijkstra, State};
+    use crate::grid::{Grid, Pos};
+
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#G";
+
+    #[derive(Clone)]
+    struct Walker<'a> {
+        maze: &'a Grid<char>,
+        pos: Pos,
+        goal: Pos,
+    }
+
+    impl State for Walker<'_> {
+        type Key = Pos;
+        type Cost = usize;
+
+        fn successors(&self) -> Vec<(Self, usize)> {
+            self.maze
+                .neighbors4(self.pos)
+                .filter(|&p| self.maze[p] != '#')
+                .map(|pos| (Walker { pos, ..*self }, 1))
+                .collect()
+        }
+
+        fn is_goal(&self) -> bool {
+            self.pos == self.goal
+        }
+
+        fn key(&self) -> Pos {
+            self.pos
+        }
+
+        fn heuristic(&self) -> usize {
+            self.pos.0.abs_diff(self.goal.0) + self.pos.1.abs_diff(self.goal.1)
+        }
+    }
+
+    /// Nodes `0..=3` where `0 -> 3` costs 10 and `0 -> 1 -> 2 -> 3` costs 3.
+    #[derive(Clone)]
+    struct Weighted(u8);
+
+    impl State for Weighted {
+        type Key = u8;
+        type Cost = u32;
+
+        fn successors(&self) -> Vec<(Self, u32)> {
+            match self.0 {
+                0 => vec![(Weighted(3), 10), (Weighted(1), 1)],
+                1 => vec![(Weighted(2), 1)],
+                2 => vec![(Weighted(3), 1)],
+                _ => vec![],
+            }
+        }
+
+        fn is_goal(&self) -> bool {
+            self.0 == 3
+        }
+
+        fn key(&self) -> u8 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn solves_mazes() {
+        let maze = Grid::parse(MAZE, |c| c);
+        let start = Walker {
+            maze: &maze,
+            pos: (0, 0),
+            goal: (4, 7),
+        };
+
+        let outcomes = [
+            bfs(start.clone()),
+            dijkstra(start.clone()),
+            astar(start.clone()),
+            branch_and_bound(start),
+        ];
+        for outcome in &outcomes {
+            assert_eq!(outcome.cost(), Some(15));
+            let path = outcome.path.as_ref().unwrap();
+            assert_eq!(path.states.len(), 16);
+            assert_eq!(path.states.last().unwrap().pos, (4, 7));
+        }
+        assert!(outcomes[2].stats.expanded <= outcomes[1].stats.expanded);
+    }
+
+    #[test]
+    fn weighs_steps() {
+        assert_eq!(bfs(Weighted(0)).cost(), Some(10));
+        assert_eq!(dijkstra(Weighted(0)).cost(), Some(3));
+        assert_eq!(branch_and_bound(Weighted(0)).cost(), Some(3));
+
+        let keys: Vec<u8> = astar(Weighted(0))
+            .path
+            .unwrap()
+            .states
+            .iter()
+            .map(|s| s.0)
+            .collect();
+        assert_eq!(keys, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn reports_unreachable_goals() {
+        let outcome = bfs(Weighted(4));
+        assert!(outcome.path.is_none());
+        assert_eq!(outcome.stats.expanded, 1);
+    }
+}
@@ -0,0 +1,31 @@
+//! Registry of the solutions in `src/bin`, compiled into the library so they can be called
+//! without spawning a binary. The list of days is generated by `build.rs`, which includes copies of
+//! the day modules without their `main`, global allocator and tests.
+#![allow(dead_code)]
+
+use crate::template::Day;
+
+/// The part functions of a day, with answers converted to strings.
+pub struct Solution {
+    pub day: Day,
+    pub parts: [fn(&str) -> Option<String>; 2],
+}
+
+macro_rules! register {
+    ($module:ident, $day:expr) => {
+        Solution {
+            day: $crate::day!($day),
+            parts: [
+                |input| $module::part_one(input).map(|x| x.to_string()),
+                |input| $module::part_two(input).map(|x| x.to_string()),
+            ],
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+/// Returns the solution of `day`, if it has been scaffolded.
+pub fn get(day: Day) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|s| s.day == day)
+}
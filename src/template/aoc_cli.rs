@@ -1,6 +1,7 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    io::{BufRead, BufReader},
     process::{Command, Output, Stdio},
 };
 
@@ -77,7 +78,12 @@ pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandErro
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+    call_aoc_cli_captured(&args)
+}
+
+/// Returns `true` if the output of a `submit` call reports an accepted answer.
+pub fn is_right_answer(output: &Output) -> bool {
+    String::from_utf8_lossy(&output.stdout).contains("That's the right answer")
 }
 
 fn get_input_path(day: Day) -> String {
@@ -123,3 +129,34 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+/// Like [`call_aoc_cli`], but also captures stdout while forwarding it to the terminal.
+fn call_aoc_cli_captured(args: &[String]) -> Result<Output, AocCommandError> {
+    let mut cmd = Command::new("aoc")
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    let mut stdout = vec![];
+
+    if let Some(pipe) = cmd.stdout.take() {
+        for line in BufReader::new(pipe).lines().map_while(Result::ok) {
+            println!("{line}");
+            stdout.extend_from_slice(line.as_bytes());
+            stdout.push(b'\n');
+        }
+    }
+
+    let mut output = cmd
+        .wait_with_output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+    output.stdout = stdout;
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
+}
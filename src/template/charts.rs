@@ -0,0 +1,352 @@
+/// Module that renders benchmark timings as SVG charts, referenced from the readme.
+/// Durations span several orders of magnitude, so all charts use a logarithmic scale.
+use std::{fmt::Write, fs, io, path::Path};
+
+use crate::template::timings::{PartTiming, Timing, Timings};
+use crate::template::{all_days, Day};
+
+pub static CHARTS_DIR: &str = "./.assets/benchmarks";
+
+const PART_COLORS: [&str; 2] = ["#4c9be8", "#f2a541"];
+const MARGIN_LEFT: f64 = 60.0;
+const MARGIN_RIGHT: f64 = 20.0;
+const PLOT_TOP: f64 = 30.0;
+const PLOT_BOTTOM: f64 = 220.0;
+const HEIGHT: f64 = 250.0;
+
+#[must_use]
+pub fn overview_path() -> String {
+    format!("{CHARTS_DIR}/overview.svg")
+}
+
+#[must_use]
+pub fn history_path(day: Day) -> String {
+    format!("{CHARTS_DIR}/{day}.svg")
+}
+
+/// Charts that have been written to disk.
+#[derive(Debug, Default, PartialEq)]
+pub struct Charts {
+    pub overview: Option<String>,
+    pub history: Vec<(Day, String)>,
+}
+
+impl Charts {
+    /// Find the charts written by a previous run.
+    pub fn find() -> Self {
+        let existing = |path: String| Path::new(&path).exists().then_some(path);
+
+        Charts {
+            overview: existing(overview_path()),
+            history: all_days()
+                .filter_map(|day| existing(history_path(day)).map(|path| (day, path)))
+                .collect(),
+        }
+    }
+}
+
+/// Write the overview chart and a history chart for every day with more than one recorded run.
+pub fn write(timings: &Timings, history: &Timings) -> Result<(), io::Error> {
+    fs::create_dir_all(CHARTS_DIR)?;
+
+    if let Some(svg) = overview_chart(timings) {
+        fs::write(overview_path(), svg)?;
+    }
+
+    for day in all_days() {
+        let runs: Vec<&Timing> = history.data.iter().filter(|t| t.day == day).collect();
+        if let Some(svg) = history_chart(day, &runs) {
+            fs::write(history_path(day), svg)?;
+        }
+    }
+
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A logarithmic y axis spanning whole powers of ten.
+struct LogScale {
+    min_exp: i32,
+    max_exp: i32,
+}
+
+impl LogScale {
+    fn new<'a>(nanos: impl Iterator<Item = &'a PartTiming>) -> Option<Self> {
+        let exps: Vec<f64> = nanos.map(|p| p.mean_nanos.max(1.0).log10()).collect();
+
+        let min = exps.iter().copied().reduce(f64::min)?;
+        let max = exps.iter().copied().reduce(f64::max)?;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let (min_exp, max_exp) = (min.floor() as i32, max.ceil() as i32);
+
+        Some(LogScale {
+            min_exp,
+            max_exp: max_exp.max(min_exp + 1),
+        })
+    }
+
+    fn y(&self, nanos: f64) -> f64 {
+        let exp = nanos.max(1.0).log10();
+        let share = (exp - f64::from(self.min_exp)) / f64::from(self.max_exp - self.min_exp);
+        PLOT_BOTTOM - share * (PLOT_BOTTOM - PLOT_TOP)
+    }
+
+    fn grid(&self, svg: &mut String, width: f64) {
+        for exp in self.min_exp..=self.max_exp {
+            let y = self.y(10_f64.powi(exp));
+            let _ = write!(
+                svg,
+                r##"<line x1="{MARGIN_LEFT}" y1="{y:.1}" x2="{:.1}" y2="{y:.1}" stroke="#ddd"/><text x="{:.1}" y="{:.1}" font-size="10" text-anchor="end" fill="#666">{}</text>"##,
+                width - MARGIN_RIGHT,
+                MARGIN_LEFT - 6.0,
+                y + 3.0,
+                format_tick(exp)
+            );
+        }
+    }
+}
+
+/// Label of a power of ten in nanoseconds, e.g. `100µs`.
+fn format_tick(exp: i32) -> String {
+    let (unit, base) = match exp {
+        ..=2 => ("ns", 0),
+        3..=5 => ("µs", 3),
+        6..=8 => ("ms", 6),
+        _ => ("s", 9),
+    };
+    format!(
+        "{}{unit}",
+        10_u64.pow(u32::try_from(exp - base).unwrap_or(0))
+    )
+}
+
+fn open_svg(width: f64, title: &str) -> String {
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width:.0}" height="{HEIGHT:.0}" viewBox="0 0 {width:.0} {HEIGHT:.0}" font-family="sans-serif"><title>{title}</title><rect width="100%" height="100%" fill="#fff"/>"##
+    )
+}
+
+fn legend(svg: &mut String, width: f64) {
+    for (i, color) in PART_COLORS.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let x = width - MARGIN_RIGHT - 120.0 + i as f64 * 60.0;
+        let _ = write!(
+            svg,
+            r##"<rect x="{x:.1}" y="8" width="10" height="10" fill="{color}"/><text x="{:.1}" y="17" font-size="11" fill="#333">Part {}</text>"##,
+            x + 14.0,
+            i + 1
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Bar chart of the mean duration of each part, grouped by day.
+pub fn overview_chart(timings: &Timings) -> Option<String> {
+    const GROUP_WIDTH: f64 = 36.0;
+    const BAR_WIDTH: f64 = 12.0;
+
+    let days: Vec<&Timing> = timings
+        .data
+        .iter()
+        .filter(|t| t.part_1.is_some() || t.part_2.is_some())
+        .collect();
+
+    let scale = LogScale::new(days.iter().flat_map(|t| [&t.part_1, &t.part_2]).flatten())?;
+
+    #[allow(clippy::cast_precision_loss)]
+    let width = MARGIN_LEFT + days.len() as f64 * GROUP_WIDTH + MARGIN_RIGHT;
+
+    let mut svg = open_svg(width, "Benchmarks");
+    scale.grid(&mut svg, width);
+    legend(&mut svg, width);
+
+    for (i, timing) in days.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let x = MARGIN_LEFT + i as f64 * GROUP_WIDTH + (GROUP_WIDTH - 2.0 * BAR_WIDTH) / 2.0;
+
+        for (part, (color, timing)) in PART_COLORS
+            .iter()
+            .zip([&timing.part_1, &timing.part_2])
+            .enumerate()
+        {
+            let Some(timing) = timing else {
+                continue;
+            };
+            let y = scale.y(timing.mean_nanos);
+            #[allow(clippy::cast_precision_loss)]
+            let _ = write!(
+                svg,
+                r#"<rect x="{:.1}" y="{y:.1}" width="{BAR_WIDTH}" height="{:.1}" fill="{color}"><title>Part {}: {timing}</title></rect>"#,
+                x + part as f64 * BAR_WIDTH,
+                PLOT_BOTTOM - y,
+                part + 1
+            );
+        }
+
+        let _ = write!(
+            svg,
+            r##"<text x="{:.1}" y="{:.1}" font-size="11" text-anchor="middle" fill="#333">{}</text>"##,
+            x + BAR_WIDTH,
+            PLOT_BOTTOM + 16.0,
+            timing.day.into_inner()
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    Some(svg)
+}
+
+/// Line chart of the mean duration of each part over the recorded runs of a day.
+pub fn history_chart(day: Day, runs: &[&Timing]) -> Option<String> {
+    const WIDTH: f64 = 480.0;
+
+    if runs.len() < 2 {
+        return None;
+    }
+
+    let scale = LogScale::new(runs.iter().flat_map(|t| [&t.part_1, &t.part_2]).flatten())?;
+    #[allow(clippy::cast_precision_loss)]
+    let step = (WIDTH - MARGIN_LEFT - MARGIN_RIGHT - 20.0) / (runs.len() - 1) as f64;
+    #[allow(clippy::cast_precision_loss)]
+    let x = |i: usize| MARGIN_LEFT + 10.0 + i as f64 * step;
+
+    let mut svg = open_svg(
+        WIDTH,
+        &format!("Day {} benchmark history", day.into_inner()),
+    );
+    scale.grid(&mut svg, WIDTH);
+    legend(&mut svg, WIDTH);
+
+    for (part, color) in PART_COLORS.iter().enumerate() {
+        let points: Vec<(f64, f64)> = runs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, t)| {
+                let timing = if part == 0 { &t.part_1 } else { &t.part_2 };
+                timing.as_ref().map(|p| (x(i), scale.y(p.mean_nanos)))
+            })
+            .collect();
+
+        let polyline: Vec<String> = points
+            .iter()
+            .map(|(x, y)| format!("{x:.1},{y:.1}"))
+            .collect();
+        let _ = write!(
+            svg,
+            r#"<polyline points="{}" fill="none" stroke="{color}" stroke-width="2"/>"#,
+            polyline.join(" ")
+        );
+        for (x, y) in points {
+            let _ = write!(
+                svg,
+                r#"<circle cx="{x:.1}" cy="{y:.1}" r="3" fill="{color}"/>"#
+            );
+        }
+    }
+
+    for (i, run) in runs.iter().enumerate() {
+        let Some(metadata) = &run.metadata else {
+            continue;
+        };
+        // label the first and last run, and every few in between.
+        if i != 0 && i != runs.len() - 1 && i % 4 != 0 {
+            continue;
+        }
+        let _ = write!(
+            svg,
+            r##"<text x="{:.1}" y="{:.1}" font-size="10" text-anchor="middle" fill="#666">{}</text>"##,
+            x(i),
+            PLOT_BOTTOM + 16.0,
+            format_date(metadata.recorded_at)
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    Some(svg)
+}
+
+/// Format a unix timestamp as `YYYY-MM-DD`.
+/// See: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn format_date(timestamp: u64) -> String {
+    let days = i64::try_from(timestamp / 86400).unwrap_or_default() + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_date, format_tick, history_chart, overview_chart};
+    use crate::day;
+    use crate::template::timings::{PartTiming, RunMetadata, Timing, Timings};
+
+    fn timing(day: u8, part_1: f64, part_2: Option<f64>) -> Timing {
+        Timing {
+            part_1: Some(PartTiming::new(part_1)),
+            part_2: part_2.map(PartTiming::new),
+            ..Timing::new(crate::template::Day::new(day).unwrap())
+        }
+    }
+
+    #[test]
+    fn formats_ticks() {
+        assert_eq!(format_tick(0), "1ns");
+        assert_eq!(format_tick(4), "10µs");
+        assert_eq!(format_tick(8), "100ms");
+        assert_eq!(format_tick(10), "10s");
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(1_701_388_800), "2023-12-01");
+    }
+
+    #[test]
+    fn renders_overview_chart() {
+        let timings = Timings {
+            data: vec![timing(1, 1e3, Some(1e6)), timing(25, 50.0, None)],
+        };
+        let svg = overview_chart(&timings).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<title>Part").count(), 3);
+        // 50ns to 1ms spans the powers of ten from 10ns to 1ms.
+        assert!(svg.contains(">10ns<") && svg.contains(">1ms<"));
+        assert!(!svg.contains(">1ns<"));
+        assert!(overview_chart(&Timings::default()).is_none());
+    }
+
+    #[test]
+    fn renders_history_chart() {
+        let run = |recorded_at, nanos| Timing {
+            metadata: Some(RunMetadata {
+                recorded_at,
+                release: true,
+                rustc: None,
+                os: "linux".into(),
+                arch: "x86_64".into(),
+            }),
+            ..timing(1, nanos, Some(nanos * 2.0))
+        };
+        let first = run(0, 1e6);
+        let second = run(86400, 5e5);
+
+        assert!(history_chart(day!(1), &[&first]).is_none());
+
+        let svg = history_chart(day!(1), &[&first, &second]).unwrap();
+        assert_eq!(svg.matches("<polyline").count(), 2);
+        assert_eq!(svg.matches("<circle").count(), 4);
+        assert!(svg.contains(">1970-01-02<"));
+    }
+}
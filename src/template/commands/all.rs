@@ -1,5 +1,18 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::report;
+use crate::template::run_multi::{resolve_day_set, run_multi};
+use crate::template::timings::Timings;
+use crate::template::{all_days, DaySet, Format};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(days: Option<DaySet>, is_release: bool, format: Format) {
+    let days_to_run = match days {
+        Some(days) => resolve_day_set(&days, &Timings::read_from_file()),
+        None => all_days().collect(),
+    };
+
+    let is_quiet = format != Format::Text;
+    let run = run_multi(&days_to_run, is_release, false, is_quiet);
+
+    if is_quiet {
+        println!("{}", report::render(format, &run.parts, None));
+    }
 }
@@ -1,6 +1,17 @@
 pub mod all;
 pub mod download;
+pub mod next;
+pub mod progress;
 pub mod read;
+pub mod refresh;
 pub mod scaffold;
+#[cfg(feature = "serve")]
+pub mod serve;
+pub mod site;
 pub mod solve;
+pub mod start;
+pub mod status;
+pub mod submit;
+pub mod test;
 pub mod time;
+pub mod tui;
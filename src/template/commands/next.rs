@@ -0,0 +1,20 @@
+use std::process;
+
+use crate::template::commands::start;
+use crate::template::progress::Progress;
+use crate::template::Day;
+
+/// Returns the earliest day that is neither complete nor skipped. Exits if there is none.
+pub fn next_day() -> Day {
+    match Progress::read_from_file().next_day() {
+        Some(day) => day,
+        None => {
+            println!("🎄 All days are complete.");
+            process::exit(0);
+        }
+    }
+}
+
+pub fn handle() {
+    start::handle(next_day());
+}
@@ -0,0 +1,24 @@
+use std::{env, path::Path};
+
+use crate::template::progress::{Progress, PROGRESS_FILE_PATH};
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+pub fn handle() {
+    let progress = Progress::read_from_file();
+
+    // persist progress imported from the legacy puzzle markers.
+    if !Path::new(PROGRESS_FILE_PATH).exists() {
+        match progress.store_file() {
+            Ok(()) => println!("Imported progress into \"{PROGRESS_FILE_PATH}\"."),
+            Err(e) => eprintln!("Failed to store progress: {e}"),
+        }
+        println!();
+    }
+
+    match env::var("AOC_YEAR") {
+        Ok(year) => println!("{ANSI_BOLD}Advent of Code {year}{ANSI_RESET}"),
+        Err(_) => println!("{ANSI_BOLD}Advent of Code{ANSI_RESET}"),
+    }
+    println!();
+    println!("{}", progress.render_calendar());
+}
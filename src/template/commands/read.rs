@@ -1,15 +1,24 @@
-use std::process;
+use std::{fs, process};
 
-use crate::template::{aoc_cli, Day};
+use crate::template::markdown::{render, select_part, terminal_width};
+use crate::template::Day;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+/// Render the downloaded puzzle description. Does not require network access.
+pub fn handle(day: Day, part: Option<u8>) {
+    let puzzle_path = format!("data/puzzles/{day}.md");
+
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        eprintln!("Could not read \"{puzzle_path}\". Try running \"cargo download {day}\" first.");
         process::exit(1);
-    }
+    };
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    let Some(text) = select_part(&puzzle, part.unwrap_or(1)) else {
+        eprintln!("Part two of day {day} is not unlocked yet.");
         process::exit(1);
     };
+
+    // without `--part`, show the whole description.
+    let text = if part.is_some() { text } else { &puzzle };
+
+    println!("{}", render(text, terminal_width()));
 }
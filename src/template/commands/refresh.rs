@@ -0,0 +1,8 @@
+use crate::template::commands::download;
+use crate::template::{editor, Day};
+
+/// Re-download the puzzle description and input, e.g. after solving the first part.
+pub fn handle(day: Day) {
+    download::handle(day);
+    editor::open(&[format!("data/puzzles/{day}.md")]);
+}
@@ -1,79 +1,212 @@
 use std::{
-    fs::{File, OpenOptions},
+    env,
+    fs::{self, OpenOptions},
     io::Write,
+    path::Path,
     process,
+    time::{SystemTime, UNIX_EPOCH},
 };
 
-use crate::template::Day;
+use crate::template::{markdown, Day};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
-    let mut file = OpenOptions::new();
-    if overwrite {
-        file.create(true);
-    } else {
-        file.create_new(true);
-    }
-    file.truncate(true).write(true).open(path)
+static TEMPLATES_DIR: &str = "templates";
+
+/// Load a named template from `templates/{name}.txt`, or the built-in one if no name is given.
+fn load_template(name: Option<&str>) -> Result<String, String> {
+    let Some(name) = name else {
+        return Ok(MODULE_TEMPLATE.to_string());
+    };
+
+    fs::read_to_string(format!("{TEMPLATES_DIR}/{name}.txt")).map_err(|_| {
+        format!(
+            "unknown template \"{name}\", available: {}",
+            list_templates().join(", ")
+        )
+    })
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// The sources of the built-in template and of every named one.
+pub fn template_sources() -> Vec<String> {
+    let named = list_templates()
+        .into_iter()
+        .filter_map(|name| load_template(Some(&name)).ok());
+    std::iter::once(MODULE_TEMPLATE.to_string())
+        .chain(named)
+        .collect()
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+fn list_templates() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let is_template = path.extension().is_some_and(|ext| ext == "txt");
+            is_template.then(|| path.file_stem()?.to_str().map(String::from))?
+        })
+        .collect();
+    names.sort_unstable();
+    names
+}
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
-    };
+/// Returns the example files of a day, including numbered ones like `01-2.txt`.
+fn example_files(day: Day) -> Vec<String> {
+    let mut files: Vec<String> = fs::read_dir("data/examples")
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| {
+            name == &format!("{day}.txt")
+                || name.starts_with(&format!("{day}-")) && name.ends_with(".txt")
+        })
+        .map(|name| format!("data/examples/{name}"))
+        .collect();
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
-        }
+    if files.is_empty() {
+        files.push(format!("data/examples/{day}.txt"));
     }
+    files.sort_unstable();
+    files
+}
+
+/// Substitute the placeholders supported in module templates.
+fn render_template(template: &str, day: Day) -> String {
+    let year = env::var("AOC_YEAR").unwrap_or_default();
+    let title = markdown::puzzle_title(day).unwrap_or_else(|| format!("Day {}", day.into_inner()));
+
+    template
+        .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+        .replace("%DAY_PADDED%", &day.to_string())
+        .replace("%YEAR%", &year)
+        .replace("%PUZZLE_TITLE%", &title)
+        .replace(
+            "%PUZZLE_URL%",
+            &format!("https://adventofcode.com/{year}/day/{}", day.into_inner()),
+        )
+        .replace("%INPUT_FILE%", &format!("data/inputs/{day}.txt"))
+        .replace("%EXAMPLE_FILES%", &example_files(day).join(", "))
+}
+
+/// What scaffolding does to a single file.
+#[derive(Debug)]
+enum Action {
+    Create,
+    /// Replace an existing file after copying it to the contained backup path.
+    Replace(String),
+    Skip,
+}
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+struct Artifact {
+    name: &'static str,
+    path: String,
+    contents: String,
+    action: Action,
+}
+
+fn backup_path(path: &str) -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    format!("{path}.{secs}.bak")
+}
+
+/// Only the module file is ever replaced, inputs and examples are never clobbered.
+fn plan(day: Day, module: String, overwrite: bool) -> Vec<Artifact> {
+    [
+        ("module", format!("src/bin/{day}.rs"), module, overwrite),
+        (
+            "input",
+            format!("data/inputs/{day}.txt"),
+            String::new(),
+            false,
+        ),
+        (
+            "example",
+            format!("data/examples/{day}.txt"),
+            String::new(),
+            false,
+        ),
+    ]
+    .into_iter()
+    .map(|(name, path, contents, replaceable)| {
+        let action = match (Path::new(&path).exists(), replaceable) {
+            (false, _) => Action::Create,
+            (true, true) => Action::Replace(backup_path(&path)),
+            (true, false) => Action::Skip,
+        };
+        Artifact {
+            name,
+            path,
+            contents,
+            action,
         }
+    })
+    .collect()
+}
+
+fn create_file(path: &str, contents: &str) -> Result<(), std::io::Error> {
+    let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+    file.write_all(contents.as_bytes())
+}
+
+fn replace_file(path: &str, backup: &str, contents: &str) -> Result<(), std::io::Error> {
+    fs::copy(path, backup)?;
+    fs::write(path, contents)
+}
+
+pub fn handle(day: Day, template: Option<&str>, overwrite: bool, dry_run: bool) {
+    let module = match load_template(template) {
+        Ok(template) => render_template(&template, day),
         Err(e) => {
-            eprintln!("Failed to create input file: {e}");
+            eprintln!("Failed to load template: {e}");
             process::exit(1);
         }
-    }
+    };
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    for artifact in plan(day, module, overwrite) {
+        let Artifact {
+            name,
+            path,
+            contents,
+            action,
+        } = artifact;
+
+        if dry_run {
+            match action {
+                Action::Create => println!("Would create {name} file \"{path}\""),
+                Action::Replace(backup) => {
+                    println!("Would replace {name} file \"{path}\" (backup: \"{backup}\")");
+                }
+                Action::Skip => println!("Would keep existing {name} file \"{path}\""),
+            }
+            continue;
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+
+        let result = match &action {
+            Action::Create => create_file(&path, &contents),
+            Action::Replace(backup) => replace_file(&path, backup, &contents),
+            Action::Skip => Ok(()),
+        };
+
+        match (result, action) {
+            (Ok(()), Action::Create) => println!("Created {name} file \"{path}\""),
+            (Ok(()), Action::Replace(backup)) => {
+                println!("Replaced {name} file \"{path}\" (backup: \"{backup}\")");
+            }
+            (Ok(()), Action::Skip) => println!("Kept existing {name} file \"{path}\""),
+            (Err(e), _) => {
+                eprintln!("Failed to write {name} file \"{path}\": {e}");
+                process::exit(1);
+            }
         }
     }
 
-    println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if !dry_run {
+        println!("---");
+        println!("🎄 Type `cargo solve {day}` to run your solution.");
+    }
 }
@@ -0,0 +1,306 @@
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{Ipv4Addr, TcpListener, TcpStream},
+    panic, process,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::solutions;
+use crate::template::Day;
+
+pub const DEFAULT_PORT: u16 = 3025;
+
+/// Inputs are a few kilobytes, anything much larger is a mistake.
+const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+/// Requests are handled one at a time, so a client that stops sending must not block the others.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Response {
+    status: u16,
+    body: String,
+}
+
+#[derive(Serialize)]
+struct SolveResponse {
+    day: Day,
+    part: u8,
+    /// `None` if the part is not solved yet.
+    answer: Option<String>,
+    nanos: f64,
+    duration: String,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+impl Response {
+    fn json(status: u16, body: &impl Serialize) -> Self {
+        Response {
+            status,
+            body: serde_json::to_string(body).unwrap_or_default(),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Response::json(
+            status,
+            &ErrorResponse {
+                error: message.into(),
+            },
+        )
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        413 => "Payload Too Large",
+        _ => "Internal Server Error",
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Read a request. Only bodies with a `Content-Length` are supported.
+fn read_request(reader: &mut impl BufRead) -> Result<Request, Response> {
+    let bad_request = |e: io::Error| match e.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => {
+            Response::error(408, "timed out waiting for the request")
+        }
+        _ => Response::error(400, "malformed request"),
+    };
+
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(bad_request)?;
+    let mut request_line = line.split_whitespace();
+    let (Some(method), Some(path)) = (request_line.next(), request_line.next()) else {
+        return Err(Response::error(400, "malformed request line"));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut content_length = 0;
+    loop {
+        line.clear();
+        reader.read_line(&mut line).map_err(bad_request)?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| Response::error(400, "invalid content-length"))?;
+            }
+        }
+    }
+
+    if content_length > MAX_BODY_BYTES {
+        return Err(Response::error(413, "input is too large"));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(bad_request)?;
+
+    Ok(Request { method, path, body })
+}
+
+/// Dispatch a request to the matching route.
+fn route(request: &Request) -> Response {
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+
+    let ["solve", day, part] = segments.as_slice() else {
+        return Response::error(404, format!("no route for {}", request.path));
+    };
+
+    if request.method != "POST" {
+        return Response::error(405, "expected a POST request with the input as body");
+    }
+
+    let Ok(day) = day.parse::<Day>() else {
+        return Response::error(400, format!("invalid day `{day}`"));
+    };
+    let part = match *part {
+        "1" => 1,
+        "2" => 2,
+        x => return Response::error(400, format!("invalid part `{x}`, expecting 1 or 2")),
+    };
+    let Ok(input) = std::str::from_utf8(&request.body) else {
+        return Response::error(400, "input is not valid utf-8");
+    };
+
+    solve(day, part, input)
+}
+
+fn solve(day: Day, part: u8, input: &str) -> Response {
+    let Some(solution) = solutions::get(day) else {
+        return Response::error(404, format!("day {day} is not solved in this repository"));
+    };
+    let func = solution.parts[usize::from(part - 1)];
+
+    let timer = Instant::now();
+    let result = panic::catch_unwind(|| func(input));
+    let elapsed = timer.elapsed();
+
+    match result {
+        #[allow(clippy::cast_precision_loss)]
+        Ok(answer) => Response::json(
+            200,
+            &SolveResponse {
+                day,
+                part,
+                answer,
+                nanos: elapsed.as_nanos() as f64,
+                duration: format!("{elapsed:.1?}"),
+            },
+        ),
+        Err(_) => Response::error(500, "the solution panicked, see the server log"),
+    }
+}
+
+fn handle_connection(stream: &TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(stream);
+
+    let (label, response) = match read_request(&mut reader) {
+        Ok(request) => (
+            format!("{} {}", request.method, request.path),
+            route(&request),
+        ),
+        Err(response) => ("-".into(), response),
+    };
+
+    println!("{label} → {}", response.status);
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+/// Serve the solutions compiled into the library on localhost.
+/// Requests are handled one at a time, so timings are not skewed by concurrent runs.
+pub fn handle(port: Option<u16>) {
+    let port = port.unwrap_or(DEFAULT_PORT);
+
+    let listener = match TcpListener::bind((Ipv4Addr::LOCALHOST, port)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Failed to listen on port {port}: {e}");
+            process::exit(1);
+        }
+    };
+
+    let days: Vec<String> = solutions::SOLUTIONS
+        .iter()
+        .map(|s| s.day.into_inner().to_string())
+        .collect();
+    println!(
+        "Serving days {} on http://localhost:{port}",
+        days.join(", ")
+    );
+    println!("Usage: curl --data-binary @data/inputs/01.txt http://localhost:{port}/solve/1/1");
+    println!();
+
+    for stream in listener.incoming() {
+        let result = stream.and_then(|stream| handle_connection(&stream));
+        if let Err(e) = result {
+            eprintln!("Failed to handle request: {e}");
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::io::{self, BufReader, Read};
+
+    use super::{read_request, route, Request};
+
+    /// Sends the start of a request, then stalls like a socket whose read timeout expired.
+    struct Stalled(&'static [u8]);
+
+    impl Read for Stalled {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.0.is_empty() {
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+            self.0.read(buf)
+        }
+    }
+
+    fn request(method: &str, path: &str, body: &str) -> Request {
+        Request {
+            method: method.into(),
+            path: path.into(),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn reads_requests() {
+        let raw = "POST /solve/1/2 HTTP/1.1\r\nHost: localhost\r\nContent-Length: 5\r\n\r\n()())";
+        let request = read_request(&mut raw.as_bytes()).unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/solve/1/2");
+        assert_eq!(request.body, b"()())");
+    }
+
+    #[test]
+    fn rejects_incomplete_requests() {
+        let truncated = "POST /solve/1/2 HTTP/1.1\r\nContent-Length: 50\r\n\r\n()())";
+        let response = read_request(&mut truncated.as_bytes()).err().unwrap();
+        assert_eq!(response.status, 400);
+
+        let stalled = Stalled(b"POST /solve/1/2 HTTP/1.1\r\nContent-Length: 50\r\n\r\n()())");
+        let response = read_request(&mut BufReader::new(stalled)).err().unwrap();
+        assert_eq!(response.status, 408);
+
+        let stalled = Stalled(b"POST /solve/1/2 HTTP/1.1\r\nHost: loc");
+        let response = read_request(&mut BufReader::new(stalled)).err().unwrap();
+        assert_eq!(response.status, 408);
+    }
+
+    #[test]
+    fn solves_parts() {
+        let response = route(&request("POST", "/solve/1/1", "(()(()("));
+        assert_eq!(response.status, 200);
+        let value: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(value["day"], 1);
+        assert_eq!(value["part"], 1);
+        assert_eq!(value["answer"], "3");
+        assert!(value["nanos"].is_number());
+    }
+
+    #[test]
+    fn rejects_invalid_requests() {
+        assert_eq!(route(&request("GET", "/solve/1/1", "")).status, 405);
+        assert_eq!(route(&request("POST", "/solve/26/1", "")).status, 400);
+        assert_eq!(route(&request("POST", "/solve/1/3", "")).status, 400);
+        assert_eq!(route(&request("POST", "/", "")).status, 404);
+    }
+}
@@ -0,0 +1,318 @@
+use std::{env, fmt::Write, fs, io, path::Path, process};
+
+use crate::template::charts::{history_chart, overview_chart};
+use crate::template::html::{escape, highlight_rust, render_markdown};
+use crate::template::markdown::puzzle_title;
+use crate::template::progress::Progress;
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::timings::{format_nanos, PartTiming, Timing, Timings};
+use crate::template::{all_days, Day};
+
+pub static DEFAULT_SITE_DIR: &str = "./target/site";
+
+static STYLESHEET: &str = r"
+body { margin: 0 auto; max-width: 960px; padding: 1em 2em; font-family: sans-serif; line-height: 1.5; color: #222; }
+nav { display: flex; justify-content: space-between; margin-bottom: 1em; }
+a { color: #1f6fb2; }
+h1 { margin-bottom: 0.2em; }
+h2 { font-size: 1.2em; margin-top: 1.5em; }
+em { font-style: normal; font-weight: bold; }
+code, pre { font-family: ui-monospace, monospace; font-size: 0.9em; }
+pre { background: #f6f8fa; border: 1px solid #ddd; border-radius: 4px; overflow-x: auto; padding: 0.8em; }
+table { border-collapse: collapse; margin: 1em 0; }
+th, td { border-bottom: 1px solid #ddd; padding: 0.3em 0.8em; text-align: left; }
+.puzzle { border-left: 3px solid #ddd; padding-left: 1em; }
+.muted { color: #777; }
+.kw { color: #a626a4; }
+.ty { color: #c18401; }
+.mac { color: #4078f2; }
+.str { color: #50a14f; }
+.num { color: #986801; }
+.com { color: #a0a1a7; font-style: italic; }
+.attr { color: #0184bc; }
+.life { color: #e45649; }
+";
+
+/// Everything known about a single day.
+struct DayPage {
+    day: Day,
+    title: Option<String>,
+    puzzle: Option<String>,
+    source: Option<String>,
+}
+
+impl DayPage {
+    fn read(day: Day) -> Option<Self> {
+        let page = DayPage {
+            day,
+            title: puzzle_title(day),
+            puzzle: fs::read_to_string(format!("data/puzzles/{day}.md")).ok(),
+            source: fs::read_to_string(get_path_for_bin(day)).ok(),
+        };
+
+        (page.puzzle.is_some() || page.source.is_some()).then_some(page)
+    }
+
+    fn heading(&self) -> String {
+        match &self.title {
+            Some(title) => format!("Day {}: {}", self.day.into_inner(), escape(title)),
+            None => format!("Day {}", self.day.into_inner()),
+        }
+    }
+}
+
+fn page_path(day: Day) -> String {
+    format!("{day}.html")
+}
+
+fn format_timing(timing: Option<&PartTiming>) -> String {
+    timing.map_or_else(|| "-".into(), ToString::to_string)
+}
+
+fn layout(title: &str, nav: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+        <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+        <title>{title}</title>\n<link rel=\"stylesheet\" href=\"style.css\">\n</head>\n\
+        <body>\n<nav>{nav}</nav>\n{body}\n</body>\n</html>\n"
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn render_index(pages: &[DayPage], timings: &Timings, progress: &Progress, year: &str) -> String {
+    let mut body = format!("<h1>Advent of Code {year}</h1>\n");
+    let _ = writeln!(
+        body,
+        "<p><strong>Stars: {}/50</strong>",
+        progress.total_stars()
+    );
+    if !timings.data.is_empty() {
+        let _ = write!(body, " · Total: {:.2}ms", timings.total_millis());
+    }
+    body.push_str("</p>\n");
+
+    if let Some(svg) = overview_chart(timings) {
+        body.push_str(&svg);
+    }
+
+    body.push_str(
+        "<table>\n<tr><th>Day</th><th>Puzzle</th><th>Stars</th><th>Part 1</th><th>Part 2</th></tr>\n",
+    );
+
+    for page in pages {
+        let timing = timings.data.iter().find(|t| t.day == page.day);
+        let stars = progress.get(page.day).map_or(0, |p| p.stars());
+
+        let _ = writeln!(
+            body,
+            "<tr><td><a href=\"{}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            page_path(page.day),
+            page.day.into_inner(),
+            page.title.as_deref().map(escape).unwrap_or_default(),
+            "⭐".repeat(stars.into()),
+            format_timing(timing.and_then(|t| t.part_1.as_ref())),
+            format_timing(timing.and_then(|t| t.part_2.as_ref())),
+        );
+    }
+
+    body.push_str("</table>");
+    layout(&format!("Advent of Code {year}"), "", &body)
+}
+
+fn render_timing(timing: &Timing) -> String {
+    let mut html = String::from(
+        "<table>\n<tr><th></th><th>Mean</th><th>Min</th><th>Max</th><th>Std. dev.</th><th>Samples</th></tr>\n",
+    );
+
+    for (label, part) in [
+        ("Parse", &timing.parse),
+        ("Part 1", &timing.part_1),
+        ("Part 2", &timing.part_2),
+    ] {
+        let Some(part) = part else {
+            continue;
+        };
+        let _ = writeln!(
+            html,
+            "<tr><td>{label}</td><td>{part}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            format_nanos(part.min_nanos),
+            format_nanos(part.max_nanos),
+            format_nanos(part.std_dev_nanos),
+            part.samples
+        );
+    }
+    html.push_str("</table>\n");
+
+    let mut notes = vec![];
+    if let Some(bytes) = timing.peak_memory_bytes {
+        #[allow(clippy::cast_precision_loss)]
+        notes.push(format!(
+            "peak memory {:.1} MiB",
+            bytes as f64 / 1024.0 / 1024.0
+        ));
+    }
+    if let Some(metadata) = &timing.metadata {
+        notes.push(format!("{}/{}", metadata.os, metadata.arch));
+        if let Some(rustc) = &metadata.rustc {
+            notes.push(escape(rustc));
+        }
+    }
+    if !notes.is_empty() {
+        let _ = writeln!(html, "<p class=\"muted\">{}</p>", notes.join(" · "));
+    }
+
+    html
+}
+
+fn render_day(
+    page: &DayPage,
+    neighbours: (Option<&DayPage>, Option<&DayPage>),
+    timings: &Timings,
+    history: &Timings,
+    progress: &Progress,
+) -> String {
+    let link = |page: Option<&DayPage>, label: &str| {
+        page.map_or_else(
+            || "<span></span>".into(),
+            |p| format!("<a href=\"{}\">{label}</a>", page_path(p.day)),
+        )
+    };
+    let nav = format!(
+        "{}<a href=\"index.html\">Overview</a>{}",
+        link(neighbours.0, "← Previous"),
+        link(neighbours.1, "Next →")
+    );
+
+    let mut body = format!("<h1>{}</h1>\n", page.heading());
+
+    if let Some(p) = progress.get(page.day) {
+        let answers: Vec<String> = [(1, &p.part_1), (2, &p.part_2)]
+            .into_iter()
+            .filter_map(|(part, star)| {
+                let answer = star.as_ref()?.answer.as_deref()?;
+                Some(format!("Part {part}: <code>{}</code>", escape(answer)))
+            })
+            .collect();
+        if !answers.is_empty() {
+            let _ = writeln!(body, "<p>{}</p>", answers.join(" · "));
+        }
+    }
+
+    if let Some(timing) = timings.data.iter().find(|t| t.day == page.day) {
+        body.push_str("<h2>Benchmarks</h2>\n");
+        body.push_str(&render_timing(timing));
+
+        let runs: Vec<&Timing> = history.data.iter().filter(|t| t.day == page.day).collect();
+        if let Some(svg) = history_chart(page.day, &runs) {
+            body.push_str(&svg);
+        }
+    }
+
+    if let Some(puzzle) = &page.puzzle {
+        let _ = writeln!(
+            body,
+            "<h2>Puzzle</h2>\n<div class=\"puzzle\">\n{}\n</div>",
+            render_markdown(puzzle)
+        );
+    }
+
+    if let Some(source) = &page.source {
+        let _ = writeln!(
+            body,
+            "<h2>Solution</h2>\n<pre><code>{}</code></pre>",
+            highlight_rust(source)
+        );
+    }
+
+    layout(&page.heading(), &nav, &body)
+}
+
+/// Render the site for all days that have a puzzle description or a solution into `out_dir`.
+fn build(out_dir: &Path) -> Result<usize, io::Error> {
+    let pages: Vec<DayPage> = all_days().filter_map(DayPage::read).collect();
+    let timings = Timings::read_from_file();
+    let history = Timings::read_history();
+    let progress = Progress::read_from_file();
+    let year = env::var("AOC_YEAR").unwrap_or_default();
+
+    fs::create_dir_all(out_dir)?;
+    fs::write(out_dir.join("style.css"), STYLESHEET.trim_start())?;
+    fs::write(
+        out_dir.join("index.html"),
+        render_index(&pages, &timings, &progress, &year),
+    )?;
+
+    for (i, page) in pages.iter().enumerate() {
+        let neighbours = (
+            i.checked_sub(1).and_then(|i| pages.get(i)),
+            pages.get(i + 1),
+        );
+        fs::write(
+            out_dir.join(page_path(page.day)),
+            render_day(page, neighbours, &timings, &history, &progress),
+        )?;
+    }
+
+    Ok(pages.len())
+}
+
+pub fn handle(out_dir: Option<&str>) {
+    let out_dir = Path::new(out_dir.unwrap_or(DEFAULT_SITE_DIR));
+
+    match build(out_dir) {
+        Ok(count) => println!(
+            "Rendered {count} days to \"{}\".",
+            out_dir.join("index.html").display()
+        ),
+        Err(e) => {
+            eprintln!("Failed to render site: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render_day, DayPage};
+    use crate::day;
+    use crate::template::progress::Progress;
+    use crate::template::timings::{PartTiming, Timing, Timings};
+    use crate::template::Day;
+
+    fn page(day: Day) -> DayPage {
+        DayPage {
+            day,
+            title: Some("Not <Quite> Lisp".into()),
+            puzzle: Some("To *what floor*?".into()),
+            source: Some("fn main() {}".into()),
+        }
+    }
+
+    #[test]
+    fn renders_day_pages() {
+        let timings = Timings {
+            data: vec![Timing {
+                part_1: Some(PartTiming::new(74_000.0)),
+                ..Timing::new(day!(2))
+            }],
+        };
+        let (prev, current) = (page(day!(1)), page(day!(2)));
+
+        let html = render_day(
+            &current,
+            (Some(&prev), None),
+            &timings,
+            &Timings::default(),
+            &Progress::default(),
+        );
+
+        assert!(html.contains("<title>Day 2: Not &lt;Quite&gt; Lisp</title>"));
+        assert!(html.contains("<a href=\"01.html\">← Previous</a><a href=\"index.html\">"));
+        assert!(html.contains("<tr><td>Part 1</td><td>74.0µs</td>"));
+        assert!(html.contains("<p>To <em>what floor</em>?</p>"));
+        assert!(html.contains("<span class=\"kw\">fn</span> main() {}"));
+    }
+}
@@ -1,6 +1,6 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{watch, Day};
 
 pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
@@ -32,3 +32,8 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Like [`handle`], but runs the solution again whenever the files of the day change.
+pub fn handle_watch(day: Day, release: bool, dhat: bool) -> ! {
+    watch::watch(day, || handle(day, release, dhat, None))
+}
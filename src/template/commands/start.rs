@@ -0,0 +1,19 @@
+use std::path::Path;
+
+use crate::template::commands::{download, read, scaffold};
+use crate::template::{editor, Day};
+
+pub fn handle(day: Day) {
+    let module_path = format!("src/bin/{day}.rs");
+    let puzzle_path = format!("data/puzzles/{day}.md");
+
+    println!("Day {day}");
+
+    if !Path::new(&module_path).exists() {
+        download::handle(day);
+        scaffold::handle(day, None, false, false);
+    }
+
+    read::handle(day, None);
+    editor::open(&[module_path, puzzle_path]);
+}
@@ -0,0 +1,232 @@
+use std::{fs, path::Path};
+
+use crate::template::commands::{scaffold, test};
+use crate::template::progress::Progress;
+use crate::template::run_multi::{get_path_for_bin, resolve_day_set};
+use crate::template::timings::Timings;
+use crate::template::{all_days, Day, DaySet, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// State of a single part function in a solution module.
+#[derive(Debug, PartialEq)]
+pub enum PartState {
+    Missing,
+    /// The function body is still the one generated by a scaffold template.
+    Stub,
+    Implemented,
+    /// Day 25 has no second puzzle, its star is awarded for the other 49.
+    Free,
+}
+
+/// Returns the body of `func` (e.g. `part_one`) in the source of a solution module.
+fn function_body<'a>(source: &'a str, func: &str) -> Option<&'a str> {
+    let pos = source.find(&format!("fn {func}("))?;
+    let open = source[pos..].find('{').map(|x| pos + x)?;
+
+    let mut depth = 0;
+    for (i, c) in source[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&source[open + 1..open + i]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Strips comments, whitespace and statements that only silence unused `input` warnings,
+/// so that stubs compare equal no matter how they were touched up.
+fn normalize(body: &str) -> String {
+    let mut code = String::new();
+    let mut rest = body;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("//") {
+            rest = after.find('\n').map_or("", |end| &after[end..]);
+        } else if let Some(after) = rest.strip_prefix("/*") {
+            rest = after.find("*/").map_or("", |end| &after[end + 2..]);
+        } else {
+            let c = rest.chars().next().unwrap();
+            if !c.is_whitespace() {
+                code.push(c);
+            }
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    code.replace("let_=input;", "")
+}
+
+/// Returns the state of `func` (e.g. `part_one`) in the source of a solution module.
+/// A body counts as a stub if it returns `None` right away, or if it matches the body of `func`
+/// in one of the scaffold `templates`.
+pub fn part_state(source: &str, func: &str, templates: &[String]) -> PartState {
+    let Some(body) = function_body(source, func) else {
+        return PartState::Missing;
+    };
+
+    let body = normalize(body);
+    let is_stub = body == "None"
+        || templates
+            .iter()
+            .filter_map(|template| function_body(template, func))
+            .any(|template| normalize(template) == body);
+
+    if is_stub {
+        PartState::Stub
+    } else {
+        PartState::Implemented
+    }
+}
+
+fn is_non_empty(path: &str) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+fn check(value: bool) -> &'static str {
+    if value {
+        "✔"
+    } else {
+        "✖"
+    }
+}
+
+fn format_part(state: &PartState) -> &'static str {
+    match state {
+        PartState::Missing => "-",
+        PartState::Stub => "stub",
+        PartState::Implemented => "✔",
+        PartState::Free => "free",
+    }
+}
+
+fn format_stars(progress: &Progress, day: Day) -> &'static str {
+    match progress.get(day) {
+        Some(p) if p.is_complete() => "**",
+        Some(p) if p.skipped => "--",
+        Some(p) if p.stars() == 1 => "* ",
+        _ => "  ",
+    }
+}
+
+/// Print an overview of the given days, or all of them.
+/// Running the tests of each day is opt-in, since it is slow.
+pub fn handle(days: Option<DaySet>, run_tests: bool) {
+    let timings = Timings::read_from_file();
+    let progress = Progress::read_from_file();
+    let templates = scaffold::template_sources();
+    let days_to_show = match days {
+        Some(days) => resolve_day_set(&days, &timings),
+        None => all_days().collect(),
+    };
+
+    println!(
+        "{ANSI_BOLD}{:<4} {:<7} {:<6} {:<8} {:<7} {:<7} {:<6} {:<6} Benchmark{ANSI_RESET}",
+        "Day", "Module", "Input", "Example", "Part 1", "Part 2", "Tests", "Stars"
+    );
+
+    for day in all_days().filter(|day| days_to_show.contains(day)) {
+        let module_path = get_path_for_bin(day);
+        let source = fs::read_to_string(&module_path).ok();
+        let has_module = Path::new(&module_path).exists();
+
+        let (part_1, part_2) =
+            source
+                .as_deref()
+                .map_or((PartState::Missing, PartState::Missing), |s| {
+                    (
+                        part_state(s, "part_one", &templates),
+                        part_state(s, "part_two", &templates),
+                    )
+                });
+        let part_2 = match part_2 {
+            PartState::Stub if day == 25 => PartState::Free,
+            state => state,
+        };
+
+        let tests = match (run_tests, has_module) {
+            (true, true) => check(test::run_quiet(day)),
+            _ => "-",
+        };
+
+        let benchmark = timings
+            .data
+            .iter()
+            .find(|t| t.day == day)
+            .map_or_else(String::new, |t| {
+                let complete = if timings.is_day_complete(day) {
+                    ""
+                } else {
+                    " (partial)"
+                };
+                format!(
+                    "{} / {}{complete}",
+                    t.part_1
+                        .as_ref()
+                        .map_or_else(|| "-".into(), ToString::to_string),
+                    t.part_2
+                        .as_ref()
+                        .map_or_else(|| "-".into(), ToString::to_string),
+                )
+            });
+
+        println!(
+            "{:<4} {:<7} {:<6} {:<8} {:<7} {:<7} {:<6} {:<6} {benchmark}",
+            day.to_string(),
+            check(has_module),
+            check(is_non_empty(&format!("data/inputs/{day}.txt"))),
+            check(is_non_empty(&format!("data/examples/{day}.txt"))),
+            format_part(&part_1),
+            format_part(&part_2),
+            tests,
+            format_stars(&progress, day),
+        );
+    }
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Stars:{ANSI_RESET} {ANSI_ITALIC}{}/50{ANSI_RESET}",
+        progress.total_stars()
+    );
+    if !timings.data.is_empty() {
+        println!(
+            "{ANSI_BOLD}Total (Benchmarks):{ANSI_RESET} {ANSI_ITALIC}{:.2}ms{ANSI_RESET}",
+            timings.total_millis()
+        );
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{part_state, PartState};
+
+    const TEMPLATE: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+    #[test]
+    fn detects_template_stubs() {
+        assert_eq!(part_state(TEMPLATE, "part_one", &[]), PartState::Stub);
+        assert_eq!(part_state(TEMPLATE, "part_two", &[]), PartState::Stub);
+    }
+
+    #[test]
+    fn detects_touched_up_stubs() {
+        let source = "pub fn part_one(input: &str) -> Option<u64> {\n    // TODO\n    let _ = input;\n    None /* later */\n}";
+        assert_eq!(part_state(source, "part_one", &[]), PartState::Stub);
+
+        let template = "pub fn part_one(input: &str) -> Option<u64> {\n    let grid = parse(input);\n    None\n}";
+        let source = "pub fn part_one(input: &str) -> Option<u64> {\n    let grid = parse(input); // soon\n    None\n}";
+        let templates = [template.to_string()];
+        assert_eq!(part_state(source, "part_one", &templates), PartState::Stub);
+        assert_eq!(part_state(source, "part_one", &[]), PartState::Implemented);
+    }
+
+    #[test]
+    fn detects_implemented_parts() {
+        let source = "pub fn part_one(input: &str) -> Option<u64> {\n    if true { Some(1) } else { None }\n}";
+        assert_eq!(part_state(source, "part_one", &[]), PartState::Implemented);
+        assert_eq!(part_state(source, "part_two", &[]), PartState::Missing);
+    }
+}
@@ -0,0 +1,46 @@
+use std::process;
+
+use crate::template::commands::{download, read, solve, start};
+use crate::template::progress::Progress;
+use crate::template::Day;
+
+/// Submit a part in release mode. On success, continues with the second part or the next day.
+pub fn handle(day: Day, part: Option<u8>) {
+    let progress = Progress::read_from_file();
+    let get_star = |progress: &Progress, part: u8| {
+        progress.get(day).and_then(|p| match part {
+            1 => p.part_1.clone(),
+            _ => p.part_2.clone(),
+        })
+    };
+
+    // default to the first part that has not been solved yet.
+    let part = part.unwrap_or_else(|| {
+        if get_star(&progress, 1).is_some() {
+            2
+        } else {
+            1
+        }
+    });
+
+    println!("Day {day}");
+
+    let before = get_star(&progress, part);
+    solve::handle(day, true, false, Some(part));
+
+    let progress = Progress::read_from_file();
+    let after = get_star(&progress, part);
+
+    if after.is_none() || after == before {
+        process::exit(1);
+    }
+
+    if part == 1 && day != 25 {
+        // re-download to unlock the description of the second part.
+        download::handle(day);
+        read::handle(day, Some(2));
+    } else if let Some(next) = progress.next_day() {
+        println!();
+        start::handle(next);
+    }
+}
@@ -0,0 +1,47 @@
+use std::process::{self, Command, Stdio};
+
+use crate::template::{watch, Day};
+
+/// Run the tests of a solution, optionally restricted to a single part.
+pub fn handle(day: Day, part: Option<u8>, watch_files: bool) {
+    if watch_files {
+        watch::watch(day, || {
+            run(day, part);
+        });
+    }
+
+    if !run(day, part) {
+        process::exit(1);
+    }
+}
+
+/// Run the tests of a solution with their output. Returns `true` if all tests pass.
+pub fn run(day: Day, part: Option<u8>) -> bool {
+    let mut cmd_args = vec!["test".to_string(), "--bin".to_string(), day.to_string()];
+
+    match part {
+        Some(1) => cmd_args.push("part_one".to_string()),
+        Some(2) => cmd_args.push("part_two".to_string()),
+        _ => {}
+    }
+
+    cmd_args.push("--".to_string());
+    cmd_args.push("--nocapture".to_string());
+
+    Command::new("cargo")
+        .args(&cmd_args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Run the tests of a solution without printing their output. Returns `true` if all tests pass.
+pub fn run_quiet(day: Day) -> bool {
+    Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &day.to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
@@ -1,13 +1,29 @@
-use std::collections::HashSet;
+use std::{
+    io::{self, Write},
+    process,
+};
 
-use crate::template::run_multi::run_multi;
+use crate::template::report;
+use crate::template::run_multi::{resolve_day_set, run_multi};
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{all_days, charts, readme_benchmarks, Day, DaySet, Format};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+pub fn handle(days: Option<DaySet>, run_all: bool, store: bool, format: Format) {
+    // a file that can't be read would be replaced by the new timings alone, so don't store then.
+    let stored_timings = if store {
+        match Timings::load().and_then(|t| Timings::load_history().map(|_| t)) {
+            Ok(timings) => timings,
+            Err(e) => {
+                eprintln!("{e}");
+                eprintln!("Fix or remove the file to store new benchmarks.");
+                process::exit(1);
+            }
+        }
+    } else {
+        Timings::read_from_file()
+    };
 
-    let days_to_run = day.map_or_else(
+    let days_to_run = days.map_or_else(
         || {
             if run_all {
                 all_days().collect()
@@ -18,19 +34,40 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
                     .collect()
             }
         },
-        |day| HashSet::from([day]),
+        |days| resolve_day_set(&days, &stored_timings),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let is_quiet = format != Format::Text;
+    let run = run_multi(&days_to_run, true, true, is_quiet);
+    let timings = run.timings.unwrap();
+
+    if is_quiet {
+        println!("{}", report::render(format, &run.parts, Some(&timings)));
+    }
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        if let Err(e) = merged_timings.store_file() {
+            eprintln!("Failed to store benchmarks: {e}");
+            process::exit(1);
+        }
+        if let Err(e) = timings.append_history() {
+            eprintln!("Failed to store benchmark history: {e}");
+        }
+
+        if let Err(e) = charts::write(&merged_timings, &Timings::read_history()) {
+            eprintln!("Failed to write benchmark charts: {e}");
+        }
 
-        println!();
+        if !is_quiet {
+            println!();
+        }
         match readme_benchmarks::update(merged_timings) {
             Ok(()) => {
-                println!("Stored updated benchmarks.");
+                // keep stdout parseable for machine-readable formats.
+                if !is_quiet {
+                    println!("Stored updated benchmarks.");
+                }
             }
             Err(_) => {
                 eprintln!("Failed to store updated benchmarks.");
@@ -38,3 +75,18 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         }
     }
 }
+
+/// Ask whether to benchmark and store the timings of a day, e.g. after running its solution.
+pub fn confirm_record(day: Day) {
+    print!("Record? [y/N] ");
+    let _ = io::stdout().flush();
+
+    let mut answer = String::new();
+    if io::stdin().read_line(&mut answer).is_err() {
+        return;
+    }
+
+    if answer.trim().to_lowercase().starts_with('y') {
+        handle(Some(DaySet::from(day)), false, true, Format::Text);
+    }
+}
@@ -0,0 +1,485 @@
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::{self, Read, Write},
+    path::Path,
+    process::{self, Command, Stdio},
+};
+
+use crate::template::commands::{solve, test, time};
+use crate::template::markdown::{render, terminal_size};
+use crate::template::progress::Progress;
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::timings::{format_nanos, Timings};
+use crate::template::{all_days, Day, DaySet, Format, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const ANSI_DIM: &str = "\x1b[2m";
+const ANSI_REVERSE: &str = "\x1b[7m";
+const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+const ALT_SCREEN_ON: &str = "\x1b[?1049h\x1b[?25l";
+const ALT_SCREEN_OFF: &str = "\x1b[?25h\x1b[?1049l";
+
+const GRID_COLUMNS: u8 = 5;
+const CELL_WIDTH: usize = 15;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Enter,
+    Back,
+    Interrupt,
+    Char(char),
+}
+
+/// Decode the bytes of a single key press. Escape sequences arrive in a single read.
+fn parse_key(bytes: &[u8]) -> Option<Key> {
+    match bytes {
+        [0x1b, b'[', b'A'] => Some(Key::Up),
+        [0x1b, b'[', b'B'] => Some(Key::Down),
+        [0x1b, b'[', b'C'] => Some(Key::Right),
+        [0x1b, b'[', b'D'] => Some(Key::Left),
+        [0x1b, b'[', b'5', b'~'] => Some(Key::PageUp),
+        [0x1b, b'[', b'6', b'~'] => Some(Key::PageDown),
+        [0x1b] | [0x7f] | [0x08] => Some(Key::Back),
+        [b'\r' | b'\n'] => Some(Key::Enter),
+        [0x03] => Some(Key::Interrupt),
+        _ => std::str::from_utf8(bytes)
+            .ok()?
+            .chars()
+            .next()
+            .map(Key::Char),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Switches the terminal to unbuffered input without echo, and restores it when dropped.
+struct Terminal {
+    saved: String,
+}
+
+impl Terminal {
+    fn stty(args: &[&str]) -> Option<String> {
+        let output = Command::new("stty")
+            .args(args)
+            .stdin(Stdio::inherit())
+            .stderr(Stdio::null())
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    fn enter() -> Option<Self> {
+        let saved = Self::stty(&["-g"])?;
+        let terminal = Terminal { saved };
+        terminal.raw();
+        Some(terminal)
+    }
+
+    fn raw(&self) {
+        Self::stty(&["-icanon", "-echo", "-isig", "min", "1"]);
+        print!("{ALT_SCREEN_ON}");
+        let _ = io::stdout().flush();
+    }
+
+    fn restore(&self) {
+        print!("{ALT_SCREEN_OFF}");
+        let _ = io::stdout().flush();
+        Self::stty(&[&self.saved]);
+    }
+
+    /// Wait for a key press. `Ok(None)` is an unknown key, an error means that stdin is closed
+    /// or unreadable and no more keys will come.
+    fn read_key() -> io::Result<Option<Key>> {
+        let mut buf = [0; 8];
+        match io::stdin().read(&mut buf) {
+            Ok(0) => Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(n) => Ok(parse_key(&buf[..n])),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        self.restore();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum View {
+    Grid,
+    Day,
+}
+
+/// What to do after a key press.
+#[derive(Debug, PartialEq)]
+enum Action {
+    None,
+    Quit,
+    /// Leave the dashboard to run a command with visible output.
+    Run(Task),
+    Test,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Task {
+    Solve,
+    Examples,
+    Benchmark,
+}
+
+struct App {
+    view: View,
+    selected: Day,
+    scroll: usize,
+    progress: Progress,
+    timings: Timings,
+    /// Outcome of the tests run in this session.
+    tests: HashMap<Day, bool>,
+    status: Option<String>,
+    year: Option<String>,
+}
+
+impl App {
+    fn new(progress: Progress, timings: Timings) -> Self {
+        App {
+            view: View::Grid,
+            selected: progress.next_day().unwrap_or(crate::day!(1)),
+            scroll: 0,
+            progress,
+            timings,
+            tests: HashMap::new(),
+            status: None,
+            year: env::var("AOC_YEAR").ok(),
+        }
+    }
+
+    fn reload(&mut self) {
+        self.progress = Progress::read_from_file();
+        self.timings = Timings::read_from_file();
+    }
+
+    fn select(&mut self, offset: i16) {
+        let day = i16::from(self.selected.into_inner()) + offset;
+        if let Some(day) = u8::try_from(day).ok().and_then(Day::new) {
+            self.selected = day;
+            self.scroll = 0;
+        }
+    }
+
+    fn handle_key(&mut self, key: Key, page_height: usize) -> Action {
+        self.status = None;
+
+        match (self.view, key) {
+            (_, Key::Interrupt | Key::Char('q')) => return Action::Quit,
+            (_, Key::Char('t')) => return Action::Test,
+            (View::Grid, Key::Left | Key::Char('h')) => self.select(-1),
+            (View::Grid, Key::Right | Key::Char('l')) => self.select(1),
+            (View::Grid, Key::Up | Key::Char('k')) => self.select(-i16::from(GRID_COLUMNS)),
+            (View::Grid, Key::Down | Key::Char('j')) => self.select(i16::from(GRID_COLUMNS)),
+            (View::Grid, Key::Enter) => self.view = View::Day,
+            (View::Day, Key::Back) => self.view = View::Grid,
+            (View::Day, Key::Left) => self.select(-1),
+            (View::Day, Key::Right) => self.select(1),
+            (View::Day, Key::Up | Key::Char('k')) => self.scroll = self.scroll.saturating_sub(1),
+            (View::Day, Key::Down | Key::Char('j')) => self.scroll += 1,
+            (View::Day, Key::PageUp) => self.scroll = self.scroll.saturating_sub(page_height),
+            (View::Day, Key::PageDown | Key::Char(' ')) => self.scroll += page_height,
+            (View::Day, Key::Char('s')) => return Action::Run(Task::Solve),
+            (View::Day, Key::Char('e')) => return Action::Run(Task::Examples),
+            (View::Day, Key::Char('b')) => return Action::Run(Task::Benchmark),
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    fn stars(&self, day: Day) -> &'static str {
+        match self.progress.get(day) {
+            Some(p) if p.skipped => "--",
+            Some(p) if p.stars() == 2 => "★★",
+            Some(p) if p.stars() == 1 => "★ ",
+            _ => "  ",
+        }
+    }
+
+    fn test_status(&self, day: Day) -> &'static str {
+        match self.tests.get(&day) {
+            Some(true) => "✔",
+            Some(false) => "✖",
+            None => "·",
+        }
+    }
+
+    fn benchmark(&self, day: Day) -> Option<String> {
+        let timing = self.timings.data.iter().find(|t| t.day == day)?;
+        Some(format_nanos(timing.total_nanos()))
+    }
+
+    fn header(&self) -> String {
+        let title = match &self.year {
+            Some(year) => format!("Advent of Code {year}"),
+            None => "Advent of Code".into(),
+        };
+        format!(
+            "{ANSI_BOLD}{title}{ANSI_RESET}  {}/50 ★",
+            self.progress.total_stars()
+        )
+    }
+
+    fn footer(&self, help: &str) -> String {
+        match &self.status {
+            Some(status) => format!("{ANSI_ITALIC}{status}{ANSI_RESET}"),
+            None => format!("{ANSI_DIM}{help}{ANSI_RESET}"),
+        }
+    }
+
+    fn render_grid(&self) -> Vec<String> {
+        let mut lines = vec![self.header(), String::new()];
+        let days: Vec<Day> = all_days().collect();
+
+        for row in days.chunks(GRID_COLUMNS.into()) {
+            let mut top = String::new();
+            let mut bottom = String::new();
+
+            for &day in row {
+                let scaffolded = Path::new(&get_path_for_bin(day)).exists();
+                let style = match (day == self.selected, scaffolded) {
+                    (true, _) => ANSI_REVERSE,
+                    (false, false) => ANSI_DIM,
+                    (false, true) => "",
+                };
+                let benchmark = self.benchmark(day).unwrap_or_else(|| "-".into());
+
+                top.push_str(&format!(
+                    "{style}{}{ANSI_RESET} ",
+                    pad(&format!(
+                        " Day {day} {} {}",
+                        self.stars(day),
+                        self.test_status(day)
+                    ))
+                ));
+                bottom.push_str(&format!(
+                    "{style}{}{ANSI_RESET} ",
+                    pad(&format!(" {benchmark}"))
+                ));
+            }
+
+            lines.extend([top, bottom, String::new()]);
+        }
+
+        lines.push(self.footer("←↑↓→ select · enter open · t test · q quit"));
+        lines
+    }
+
+    fn render_day(&self, width: usize, height: usize) -> Vec<String> {
+        let day = self.selected;
+        let mut lines = vec![self.header(), String::new()];
+
+        lines.push(format!(
+            "{ANSI_BOLD}Day {}{ANSI_RESET}  {}  tests {}",
+            day.into_inner(),
+            self.stars(day),
+            self.test_status(day)
+        ));
+
+        if let Some(timing) = self.timings.data.iter().find(|t| t.day == day) {
+            for (label, part) in [
+                ("Parse", &timing.parse),
+                ("Part 1", &timing.part_1),
+                ("Part 2", &timing.part_2),
+            ] {
+                if let Some(part) = part {
+                    lines.push(format!(
+                        "{label}: {part} {ANSI_DIM}(min {}, max {}, {} samples){ANSI_RESET}",
+                        format_nanos(part.min_nanos),
+                        format_nanos(part.max_nanos),
+                        part.samples
+                    ));
+                }
+            }
+        } else {
+            lines.push(format!("{ANSI_DIM}Not benchmarked yet.{ANSI_RESET}"));
+        }
+
+        if let Some(p) = self.progress.get(day) {
+            for (part, star) in [(1, &p.part_1), (2, &p.part_2)] {
+                if let Some(answer) = star.as_ref().and_then(|s| s.answer.as_deref()) {
+                    lines.push(format!("Answer {part}: {ANSI_ITALIC}{answer}{ANSI_RESET}"));
+                }
+            }
+        }
+
+        lines.push("─".repeat(width.min(80)));
+
+        let puzzle = fs::read_to_string(format!("data/puzzles/{day}.md")).map_or_else(
+            |_| format!("No puzzle description, try running \"cargo download {day}\"."),
+            |puzzle| render(&puzzle, width),
+        );
+        let body_height = height.saturating_sub(lines.len() + 2);
+        lines.extend(
+            puzzle
+                .lines()
+                .skip(self.scroll)
+                .take(body_height)
+                .map(ToString::to_string),
+        );
+
+        while lines.len() < height.saturating_sub(1) {
+            lines.push(String::new());
+        }
+
+        lines.push(self.footer(
+            "s solve · e examples · b benchmark · t test · ↑↓ scroll · ←→ day · esc back · q quit",
+        ));
+        lines
+    }
+}
+
+/// Pad plain text to the width of a grid cell.
+fn pad(s: &str) -> String {
+    let len = s.chars().count();
+    format!("{s}{}", " ".repeat(CELL_WIDTH.saturating_sub(len)))
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn draw(lines: &[String]) {
+    let mut stdout = io::stdout().lock();
+    let _ = write!(stdout, "{ANSI_CLEAR}{}", lines.join("\r\n"));
+    let _ = stdout.flush();
+}
+
+/// Leave the dashboard, run a command with its output visible and wait for a key press.
+fn run_task(terminal: &Terminal, app: &mut App, task: Task) {
+    let day = app.selected;
+    terminal.restore();
+
+    match task {
+        Task::Solve => solve::handle(day, true, false, None),
+        Task::Examples => {
+            let passed = test::run(day, None);
+            app.tests.insert(day, passed);
+        }
+        Task::Benchmark => time::handle(Some(DaySet::from(day)), false, true, Format::Text),
+    }
+
+    println!("\n{ANSI_ITALIC}Press any key to return.{ANSI_RESET}");
+    terminal.raw();
+    // if stdin is closed, the main loop stops on its next read.
+    let _ = Terminal::read_key();
+    app.reload();
+}
+
+/// Show a dashboard of all days. Requires a terminal that supports `stty`.
+pub fn handle() {
+    let Some(terminal) = Terminal::enter() else {
+        eprintln!("The dashboard requires an interactive terminal.");
+        process::exit(1);
+    };
+
+    let mut app = App::new(Progress::read_from_file(), Timings::read_from_file());
+
+    loop {
+        let (height, width) = terminal_size().unwrap_or((24, 80));
+        let width = width.min(100);
+
+        match app.view {
+            View::Grid => draw(&app.render_grid()),
+            View::Day => draw(&app.render_day(width, height)),
+        }
+
+        let key = match Terminal::read_key() {
+            Ok(Some(key)) => key,
+            Ok(None) => continue,
+            Err(_) => break,
+        };
+
+        match app.handle_key(key, height / 2) {
+            Action::None => {}
+            Action::Quit => break,
+            Action::Run(task) => run_task(&terminal, &mut app, task),
+            Action::Test => {
+                let day = app.selected;
+                app.status = Some(format!("Testing day {day}…"));
+                match app.view {
+                    View::Grid => draw(&app.render_grid()),
+                    View::Day => draw(&app.render_day(width, height)),
+                }
+                let passed = test::run_quiet(day);
+                app.tests.insert(day, passed);
+                app.status = Some(format!(
+                    "Tests of day {day} {}.",
+                    if passed { "passed" } else { "failed" }
+                ));
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_key, Action, App, Key, Task, View};
+    use crate::day;
+    use crate::template::progress::Progress;
+    use crate::template::timings::Timings;
+
+    fn app() -> App {
+        let mut app = App::new(Progress::default(), Timings::default());
+        app.selected = day!(7);
+        app
+    }
+
+    #[test]
+    fn parses_keys() {
+        assert_eq!(parse_key(b"\x1b[A"), Some(Key::Up));
+        assert_eq!(parse_key(b"\x1b[6~"), Some(Key::PageDown));
+        assert_eq!(parse_key(b"\x1b"), Some(Key::Back));
+        assert_eq!(parse_key(b"\r"), Some(Key::Enter));
+        assert_eq!(parse_key(b"s"), Some(Key::Char('s')));
+        assert_eq!(parse_key(b""), None);
+    }
+
+    #[test]
+    fn navigates_grid() {
+        let mut app = app();
+        app.handle_key(Key::Down, 10);
+        assert_eq!(app.selected, day!(12));
+        app.handle_key(Key::Left, 10);
+        assert_eq!(app.selected, day!(11));
+        app.handle_key(Key::Up, 10);
+        app.handle_key(Key::Up, 10);
+        app.handle_key(Key::Up, 10);
+        assert_eq!(app.selected, day!(1));
+        app.handle_key(Key::Left, 10);
+        assert_eq!(app.selected, day!(1));
+    }
+
+    #[test]
+    fn opens_days() {
+        let mut app = app();
+        assert_eq!(app.handle_key(Key::Char('s'), 10), Action::None);
+        app.handle_key(Key::Enter, 10);
+        assert_eq!(app.view, View::Day);
+        assert_eq!(app.handle_key(Key::Char('s'), 10), Action::Run(Task::Solve));
+        app.handle_key(Key::PageDown, 10);
+        assert_eq!(app.scroll, 10);
+        app.handle_key(Key::Back, 10);
+        assert_eq!(app.view, View::Grid);
+        assert_eq!(app.handle_key(Key::Char('q'), 10), Action::Quit);
+    }
+}
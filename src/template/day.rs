@@ -1,7 +1,10 @@
+use std::collections::HashSet;
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
 
@@ -100,6 +103,156 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
+impl Serialize for Day {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Day {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let day = u8::deserialize(deserializer)?;
+        Self::new(day).ok_or_else(|| de::Error::custom(DayFromStrError))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A single term of a [`DaySet`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DaySelector {
+    /// An inclusive range of days, e.g. `7`, `1-5` or `12..`.
+    Range(Day, Day),
+    Odd,
+    Even,
+    /// Days that have not been completed yet.
+    Unsolved,
+    /// Days whose benchmarked total runtime exceeds the given number of nanoseconds, e.g. `slow>100ms`.
+    SlowerThan(f64),
+}
+
+/// A set of days, written as comma-separated terms like `1-5,7,12..`, `odd`, `unsolved` or `slow>100ms`.
+///
+/// Terms that depend on progress or timings are evaluated by [`DaySet::resolve`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DaySet(pub Vec<DaySelector>);
+
+impl DaySet {
+    /// Returns every day matching any term.
+    /// `is_solved` and `total_nanos` provide the progress and benchmark data needed by some terms.
+    pub fn resolve(
+        &self,
+        is_solved: impl Fn(Day) -> bool,
+        total_nanos: impl Fn(Day) -> Option<f64>,
+    ) -> HashSet<Day> {
+        all_days()
+            .filter(|&day| {
+                self.0.iter().any(|selector| match selector {
+                    DaySelector::Range(from, to) => day >= *from && day <= *to,
+                    DaySelector::Odd => day.0 % 2 == 1,
+                    DaySelector::Even => day.0 % 2 == 0,
+                    DaySelector::Unsolved => !is_solved(day),
+                    DaySelector::SlowerThan(nanos) => total_nanos(day).is_some_and(|x| x > *nanos),
+                })
+            })
+            .collect()
+    }
+}
+
+impl From<Day> for DaySet {
+    fn from(day: Day) -> Self {
+        DaySet(vec![DaySelector::Range(day, day)])
+    }
+}
+
+/// Parses durations as printed by the runner, e.g. `100ms`, `1.5s` or `250µs`, into nanoseconds.
+fn parse_nanos(s: &str) -> Option<f64> {
+    let split = s.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (value, unit) = s.split_at(split);
+    let value: f64 = value.parse().ok()?;
+
+    let factor = match unit {
+        "ns" => 1_f64,
+        "µs" | "us" => 1_000_f64,
+        "ms" => 1_000_000_f64,
+        "s" => 1_000_000_000_f64,
+        _ => return None,
+    };
+
+    Some(value * factor)
+}
+
+impl FromStr for DaySelector {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || DaySetFromStrError(s.to_string());
+        let day = |x: &str| Day::from_str(x.trim()).map_err(|_| err());
+
+        match s.trim() {
+            "all" => Ok(DaySelector::Range(Day(1), Day(25))),
+            "odd" => Ok(DaySelector::Odd),
+            "even" => Ok(DaySelector::Even),
+            "unsolved" => Ok(DaySelector::Unsolved),
+            x if x.starts_with("slow>") => parse_nanos(&x["slow>".len()..])
+                .map(DaySelector::SlowerThan)
+                .ok_or_else(err),
+            x => {
+                let (from, to) = if let Some((from, to)) = x.split_once("..") {
+                    let from = if from.is_empty() { Day(1) } else { day(from)? };
+                    let to = if to.is_empty() { Day(25) } else { day(to)? };
+                    (from, to)
+                } else if let Some((from, to)) = x.split_once('-') {
+                    (day(from)?, day(to)?)
+                } else {
+                    (day(x)?, day(x)?)
+                };
+
+                if from > to {
+                    return Err(err());
+                }
+                Ok(DaySelector::Range(from, to))
+            }
+        }
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|x| !x.trim().is_empty())
+            .map(DaySelector::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(|selectors| {
+                if selectors.is_empty() {
+                    Err(DaySetFromStrError(s.to_string()))
+                } else {
+                    Ok(DaySet(selectors))
+                }
+            })
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug)]
+pub struct DaySetFromStrError(String);
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid day set `{}`, expecting terms like `1-5,7,12..`, `odd`, `unsolved` or `slow>100ms`",
+            self.0
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// An iterator that yields every day of advent from the 1st to the 25th.
 pub fn all_days() -> AllDays {
     AllDays::new()
@@ -154,7 +307,43 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DaySet};
+    use std::collections::HashSet;
+
+    fn days(xs: &[u8]) -> HashSet<Day> {
+        xs.iter().map(|&x| Day(x)).collect()
+    }
+
+    #[test]
+    fn parses_day_ranges() {
+        let set: DaySet = "1-3,7,23..".parse().unwrap();
+        let resolved = set.resolve(|_| false, |_| None);
+        assert_eq!(resolved, days(&[1, 2, 3, 7, 23, 24, 25]));
+
+        let set: DaySet = "..2".parse().unwrap();
+        assert_eq!(set.resolve(|_| false, |_| None), days(&[1, 2]));
+    }
+
+    #[test]
+    fn parses_day_filters() {
+        let set: DaySet = "odd".parse().unwrap();
+        assert_eq!(set.resolve(|_| false, |_| None).len(), 13);
+
+        let set: DaySet = "unsolved".parse().unwrap();
+        let resolved = set.resolve(|day| day.0 > 2, |_| None);
+        assert_eq!(resolved, days(&[1, 2]));
+
+        let set: DaySet = "slow>1.5ms".parse().unwrap();
+        let resolved = set.resolve(|_| false, |day| Some(f64::from(day.0) * 1_000_000_f64));
+        assert_eq!(resolved, (2..=25).map(Day).collect());
+    }
+
+    #[test]
+    fn rejects_invalid_day_sets() {
+        for s in ["", "0", "26", "5-3", "1-", "slow>100", "slow>fast", "prime"] {
+            assert!(s.parse::<DaySet>().is_err(), "{s}");
+        }
+    }
 
     #[test]
     fn all_days_iterator() {
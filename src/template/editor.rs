@@ -0,0 +1,25 @@
+/// Module that opens files in the user's editor of choice.
+use std::{env, process::Command};
+
+/// Returns the configured editor command, split into program and arguments.
+/// Reads `AOC_EDITOR` first, falling back to `VISUAL` and `EDITOR`.
+fn get_editor() -> Option<Vec<String>> {
+    ["AOC_EDITOR", "VISUAL", "EDITOR"]
+        .iter()
+        .find_map(|key| env::var(key).ok().filter(|x| !x.trim().is_empty()))
+        .map(|cmd| cmd.split_whitespace().map(String::from).collect())
+}
+
+/// Open the given paths in the configured editor without waiting for it to exit.
+/// Does nothing if no editor is configured.
+pub fn open(paths: &[String]) {
+    let Some(editor) = get_editor() else {
+        return;
+    };
+
+    let (program, args) = editor.split_first().unwrap();
+
+    if let Err(e) = Command::new(program).args(args).args(paths).spawn() {
+        eprintln!("Failed to open editor \"{program}\": {e}");
+    }
+}
@@ -0,0 +1,391 @@
+/// Module that renders puzzle descriptions and solution sources to HTML for the static site.
+/// Like the terminal renderer, it only supports the subset of markdown that aoc-cli emits.
+use crate::template::markdown::find_link_end;
+
+/// Escape text for use in HTML content and attribute values.
+pub fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Render a puzzle description to HTML.
+pub fn render_markdown(puzzle: &str) -> String {
+    let mut out: Vec<String> = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    let mut list: Vec<String> = vec![];
+    let mut lines = puzzle.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_end();
+
+        if trimmed.starts_with("```") {
+            flush(&mut out, &mut paragraph, &mut list);
+            let mut block = vec![];
+            for line in lines.by_ref() {
+                if line.trim_end().starts_with("```") {
+                    break;
+                }
+                block.push(escape(line.trim_end()));
+            }
+            while block.last().is_some_and(String::is_empty) {
+                block.pop();
+            }
+            out.push(format!("<pre><code>{}</code></pre>", block.join("\n")));
+        } else if lines.peek().is_some_and(|next| is_underline(next)) {
+            flush(&mut out, &mut paragraph, &mut list);
+            lines.next();
+            let heading = trimmed.trim_start_matches('\\');
+            out.push(format!("<h2>{}</h2>", escape(heading)));
+        } else if let Some(item) = trimmed.strip_prefix("* ") {
+            flush(&mut out, &mut paragraph, &mut vec![]);
+            list.push(format!("<li>{}</li>", render_inline(item)));
+        } else if trimmed.contains("[Share") || matches!(trimmed, "#complete" | "#skip") {
+            // skip the social links added below solved puzzles and legacy progress markers.
+            continue;
+        } else if trimmed.is_empty() {
+            flush(&mut out, &mut paragraph, &mut list);
+        } else {
+            flush(&mut out, &mut vec![], &mut list);
+            paragraph.push(trimmed);
+        }
+    }
+
+    flush(&mut out, &mut paragraph, &mut list);
+    out.join("\n")
+}
+
+/// Close the paragraph or list that is currently open.
+fn flush(out: &mut Vec<String>, paragraph: &mut Vec<&str>, list: &mut Vec<String>) {
+    if !paragraph.is_empty() {
+        out.push(format!("<p>{}</p>", render_inline(&paragraph.join(" "))));
+        paragraph.clear();
+    }
+    if !list.is_empty() {
+        out.push(format!("<ul>{}</ul>", list.concat()));
+        list.clear();
+    }
+}
+
+fn is_underline(line: &str) -> bool {
+    let line = line.trim();
+    !line.is_empty() && line.chars().all(|c| c == '-')
+}
+
+/// Resolve escapes, emphasis, inline code and links into HTML.
+fn render_inline(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    render_chars(&chars)
+}
+
+fn render_chars(chars: &[char]) -> String {
+    let mut out = String::new();
+    let mut emphasis = false;
+    let mut code = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if code {
+            if c == '`' {
+                code = false;
+                out.push_str("</code>");
+            } else {
+                out.push_str(&escape(&c.to_string()));
+            }
+        } else {
+            match c {
+                '\\' if i + 1 < chars.len() => {
+                    i += 1;
+                    out.push_str(&escape(&chars[i].to_string()));
+                }
+                '`' => {
+                    code = true;
+                    out.push_str("<code>");
+                }
+                '*' => {
+                    emphasis = !emphasis;
+                    out.push_str(if emphasis { "<em>" } else { "</em>" });
+                }
+                '[' => match find_link_end(chars, i) {
+                    Some(end) => {
+                        let (target, close) = link_target(chars, end + 1);
+                        out.push_str(&format!(
+                            "<a href=\"{}\">{}</a>",
+                            escape(&target),
+                            render_chars(&chars[i + 1..end])
+                        ));
+                        i = close;
+                    }
+                    None => out.push('['),
+                },
+                _ => out.push_str(&escape(&c.to_string())),
+            }
+        }
+
+        i += 1;
+    }
+
+    if code {
+        out.push_str("</code>");
+    }
+    if emphasis {
+        out.push_str("</em>");
+    }
+
+    out
+}
+
+/// Read the target of a link starting at the `(` at `open`, which may contain balanced parens.
+/// Returns the target and the position of the closing paren.
+fn link_target(chars: &[char], open: usize) -> (String, usize) {
+    let mut depth = 0;
+    let mut target = String::new();
+
+    for (i, &c) in chars.iter().enumerate().skip(open) {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 1 => return (target, i),
+            ')' => depth -= 1,
+            _ => {}
+        }
+        if i > open {
+            target.push(c);
+        }
+    }
+
+    (target, chars.len())
+}
+
+/* -------------------------------------------------------------------------- */
+
+const KEYWORDS: [&str; 38] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+/// Highlight Rust source code, wrapping tokens in `<span>`s with the classes
+/// `kw`, `ty`, `mac`, `str`, `num`, `com`, `attr` and `life`.
+pub fn highlight_rust(source: &str) -> String {
+    let chars: Vec<char> = source.chars().collect();
+    let mut out = String::with_capacity(source.len() * 2);
+    let mut i = 0;
+
+    let span = |out: &mut String, class: &str, token: &[char]| {
+        let token: String = token.iter().collect();
+        out.push_str(&format!(
+            "<span class=\"{class}\">{}</span>",
+            escape(&token)
+        ));
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let start = i;
+
+        if c == '/' && next == Some('/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            span(&mut out, "com", &chars[start..i]);
+        } else if c == '/' && next == Some('*') {
+            i += 2;
+            while i < chars.len() && !(chars[i - 1] == '*' && chars[i] == '/') {
+                i += 1;
+            }
+            i = (i + 1).min(chars.len());
+            span(&mut out, "com", &chars[start..i]);
+        } else if c == '#' && matches!(next, Some('[' | '!')) {
+            let mut depth = 0;
+            while i < chars.len() {
+                match chars[i] {
+                    '[' => depth += 1,
+                    ']' if depth == 1 => break,
+                    ']' => depth -= 1,
+                    _ => {}
+                }
+                i += 1;
+            }
+            i = (i + 1).min(chars.len());
+            span(&mut out, "attr", &chars[start..i]);
+        } else if let Some(end) = string_end(&chars, i) {
+            i = end;
+            span(&mut out, "str", &chars[start..i]);
+        } else if c == '\'' {
+            match char_literal_end(&chars, i) {
+                Some(end) => {
+                    i = end;
+                    span(&mut out, "str", &chars[start..i]);
+                }
+                None => {
+                    i += 1;
+                    while i < chars.len() && is_ident(chars[i]) {
+                        i += 1;
+                    }
+                    span(&mut out, "life", &chars[start..i]);
+                }
+            }
+        } else if c.is_ascii_digit() {
+            while i < chars.len()
+                && (is_ident(chars[i])
+                    || (chars[i] == '.' && chars.get(i + 1).is_some_and(char::is_ascii_digit)))
+            {
+                i += 1;
+            }
+            span(&mut out, "num", &chars[start..i]);
+        } else if is_ident(c) {
+            while i < chars.len() && is_ident(chars[i]) {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            if chars.get(i) == Some(&'!') && chars.get(i + 1) != Some(&'=') {
+                i += 1;
+                span(&mut out, "mac", &chars[start..i]);
+            } else if KEYWORDS.contains(&word.as_str()) {
+                span(&mut out, "kw", &chars[start..i]);
+            } else if c.is_ascii_uppercase() {
+                span(&mut out, "ty", &chars[start..i]);
+            } else {
+                out.push_str(&word);
+            }
+        } else {
+            out.push_str(&escape(&c.to_string()));
+            i += 1;
+        }
+    }
+
+    out
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Returns the end of a string literal (`"…"`, `b"…"`, `r#"…"#`) starting at `start`.
+fn string_end(chars: &[char], start: usize) -> Option<usize> {
+    // identifiers like `br` must not be mistaken for a prefix.
+    if start > 0 && is_ident(chars[start - 1]) {
+        return None;
+    }
+
+    let mut i = start;
+    if chars.get(i) == Some(&'b') {
+        i += 1;
+    }
+
+    if chars.get(i) == Some(&'r') {
+        i += 1;
+        let mut hashes = 0;
+        while chars.get(i) == Some(&'#') {
+            hashes += 1;
+            i += 1;
+        }
+        if chars.get(i) != Some(&'"') {
+            return None;
+        }
+        i += 1;
+        while i < chars.len() {
+            if chars[i] == '"' && (1..=hashes).all(|h| chars.get(i + h) == Some(&'#')) {
+                return Some(i + 1 + hashes);
+            }
+            i += 1;
+        }
+        return Some(chars.len());
+    }
+
+    if chars.get(i) != Some(&'"') {
+        return None;
+    }
+    i += 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            '"' => return Some(i + 1),
+            _ => {}
+        }
+        i += 1;
+    }
+
+    Some(chars.len())
+}
+
+/// Returns the end of a char literal starting at `start`, or `None` for lifetimes and labels.
+fn char_literal_end(chars: &[char], start: usize) -> Option<usize> {
+    match chars.get(start + 1)? {
+        '\\' => {
+            let close = chars[start + 2..].iter().position(|&c| c == '\'')?;
+            Some(start + 2 + close + 1)
+        }
+        _ => (chars.get(start + 2) == Some(&'\'')).then_some(start + 3),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{escape, highlight_rust, render_markdown};
+
+    #[test]
+    fn escapes_html() {
+        assert_eq!(
+            escape("a < b && \"c\""),
+            "a &lt; b &amp;&amp; &quot;c&quot;"
+        );
+    }
+
+    #[test]
+    fn renders_markdown() {
+        let md = "\\--- Day 1: Not Quite Lisp ---\n----------\n\nTo *what floor*\ndo `(()` take [Santa](https://example.com/(x))?\n\n* one\n* two\n\n```\na < b\n\n```";
+        let expected = [
+            "<h2>--- Day 1: Not Quite Lisp ---</h2>",
+            "<p>To <em>what floor</em> do <code>(()</code> take <a href=\"https://example.com/(x)\">Santa</a>?</p>",
+            "<ul><li>one</li><li>two</li></ul>",
+            "<pre><code>a &lt; b</code></pre>",
+        ]
+        .join("\n");
+        assert_eq!(render_markdown(md), expected);
+    }
+
+    #[test]
+    fn skips_share_links() {
+        let md = "Answer.\n\nYou can also [Share] this puzzle.\n#complete";
+        assert_eq!(render_markdown(md), "<p>Answer.</p>");
+    }
+
+    #[test]
+    fn highlights_rust() {
+        let source =
+            "// part\n#[test]\nfn a<'a>(x: &'a str) -> Vec<u8> { println!(\"{}\", 'x'); 1_000 }";
+        let expected = [
+            "<span class=\"com\">// part</span>\n",
+            "<span class=\"attr\">#[test]</span>\n",
+            "<span class=\"kw\">fn</span> a&lt;<span class=\"life\">'a</span>&gt;",
+            "(x: &amp;<span class=\"life\">'a</span> str) -&gt; <span class=\"ty\">Vec</span>&lt;u8&gt; { ",
+            "<span class=\"mac\">println!</span>(<span class=\"str\">&quot;{}&quot;</span>, ",
+            "<span class=\"str\">'x'</span>); <span class=\"num\">1_000</span> }",
+        ]
+        .concat();
+        assert_eq!(highlight_rust(source), expected);
+    }
+
+    #[test]
+    fn highlights_raw_strings() {
+        assert_eq!(
+            highlight_rust("r#\"a\"b\"#"),
+            "<span class=\"str\">r#&quot;a&quot;b&quot;#</span>"
+        );
+    }
+}
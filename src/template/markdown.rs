@@ -0,0 +1,317 @@
+/// Module that renders the puzzle descriptions written by aoc-cli to the terminal.
+/// Only supports the subset of markdown that aoc-cli emits: headings, paragraphs,
+/// bullet lists, fenced code blocks, emphasis, inline code and links.
+use std::{
+    env, fs,
+    process::{Command, Stdio},
+};
+
+use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+static PART_TWO_HEADING: &str = "\\--- Part Two ---";
+
+const DEFAULT_WIDTH: usize = 80;
+const MAX_WIDTH: usize = 100;
+
+/// Returns the number of rows and columns of the terminal, if stdin is one.
+pub fn terminal_size() -> Option<(usize, usize)> {
+    let output = Command::new("stty")
+        .arg("size")
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    let size = String::from_utf8_lossy(&output.stdout);
+    let mut size = size.split_whitespace().map(str::parse);
+    Some((size.next()?.ok()?, size.next()?.ok()?))
+}
+
+/// Returns the width to wrap text at, capped to keep paragraphs readable.
+pub fn terminal_width() -> usize {
+    let from_env = env::var("COLUMNS").ok().and_then(|x| x.parse().ok());
+    let from_stty = || terminal_size().map(|(_, columns)| columns);
+
+    from_env
+        .or_else(from_stty)
+        .unwrap_or(DEFAULT_WIDTH)
+        .clamp(20, MAX_WIDTH)
+}
+
+/// Returns the title of the puzzle, e.g. `Not Quite Lisp`, if the description has been downloaded.
+pub fn puzzle_title(day: Day) -> Option<String> {
+    let puzzle = fs::read_to_string(format!("data/puzzles/{day}.md")).ok()?;
+    let heading = puzzle.lines().next()?.trim_start_matches('\\');
+    let title = heading.trim_matches(|c: char| c == '-' || c.is_whitespace());
+    title.split_once(": ").map(|(_, title)| title.to_string())
+}
+
+/// Returns the description of the given part, or `None` if it is not unlocked yet.
+pub fn select_part(puzzle: &str, part: u8) -> Option<&str> {
+    let part_two = puzzle.find(PART_TWO_HEADING);
+
+    match (part, part_two) {
+        (2, Some(pos)) => Some(&puzzle[pos..]),
+        (2, None) => None,
+        (_, Some(pos)) => Some(&puzzle[..pos]),
+        (_, None) => Some(puzzle),
+    }
+}
+
+/// Render markdown to text with ANSI styling, wrapped to `width` columns.
+pub fn render(puzzle: &str, width: usize) -> String {
+    let mut out: Vec<String> = vec![];
+    let mut lines = puzzle.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_end();
+
+        if trimmed.starts_with("```") {
+            let mut block = vec![];
+            for line in lines.by_ref() {
+                if line.trim_end().starts_with("```") {
+                    break;
+                }
+                block.push(line.trim_end());
+            }
+            while block.last().is_some_and(|l| l.is_empty()) {
+                block.pop();
+            }
+            out.extend(frame_code_block(&block));
+        } else if lines.peek().is_some_and(|next| is_underline(next)) {
+            lines.next();
+            let heading = trimmed.trim_start_matches('\\');
+            out.push(format!("{ANSI_BOLD}{heading}{ANSI_RESET}"));
+        } else if let Some(item) = trimmed.strip_prefix("* ") {
+            out.extend(wrap(&parse_inline(item), width, "  • ", "    "));
+        } else if trimmed.contains("[Share") || matches!(trimmed, "#complete" | "#skip") {
+            // skip the social links added below solved puzzles and legacy progress markers.
+            continue;
+        } else if trimmed.is_empty() {
+            if out.last().is_some_and(|l| !l.is_empty()) {
+                out.push(String::new());
+            }
+        } else {
+            out.extend(wrap(&parse_inline(trimmed), width, "", ""));
+        }
+    }
+
+    while out.last().is_some_and(String::is_empty) {
+        out.pop();
+    }
+
+    out.join("\n")
+}
+
+fn is_underline(line: &str) -> bool {
+    let line = line.trim();
+    !line.is_empty() && line.chars().all(|c| c == '-')
+}
+
+fn frame_code_block(block: &[&str]) -> Vec<String> {
+    let inner = block.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+
+    let mut lines = vec![format!("  ┌{}┐", "─".repeat(inner + 2))];
+    for line in block {
+        let pad = inner - line.chars().count();
+        lines.push(format!("  │ {line}{} │", " ".repeat(pad)));
+    }
+    lines.push(format!("  └{}┘", "─".repeat(inner + 2)));
+
+    lines
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Style {
+    emphasis: bool,
+    code: bool,
+}
+
+impl Style {
+    fn ansi(self) -> String {
+        let mut s = String::new();
+        if self.emphasis {
+            s.push_str(ANSI_BOLD);
+        }
+        if self.code {
+            s.push_str(ANSI_ITALIC);
+        }
+        s
+    }
+}
+
+/// Resolve escapes, emphasis, inline code and links into styled characters.
+fn parse_inline(line: &str) -> Vec<(char, Style)> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut out = vec![];
+    let mut style = Style::default();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if style.code {
+            if c == '`' {
+                style.code = false;
+            } else {
+                out.push((c, style));
+            }
+        } else {
+            match c {
+                '\\' if i + 1 < chars.len() => {
+                    i += 1;
+                    out.push((chars[i], style));
+                }
+                '`' => style.code = true,
+                '*' => style.emphasis = !style.emphasis,
+                '[' if find_link_end(&chars, i).is_some() => {}
+                ']' if chars.get(i + 1) == Some(&'(') => {
+                    // drop the link target, which may contain balanced parens.
+                    let mut depth = 0;
+                    while i + 1 < chars.len() {
+                        i += 1;
+                        match chars[i] {
+                            '(' => depth += 1,
+                            ')' if depth == 1 => break,
+                            ')' => depth -= 1,
+                            _ => {}
+                        }
+                    }
+                }
+                _ => out.push((c, style)),
+            }
+        }
+
+        i += 1;
+    }
+
+    out
+}
+
+/// Returns the position of the `](` closing a link opened at `start`.
+pub(crate) fn find_link_end(chars: &[char], start: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, &c) in chars.iter().enumerate().skip(start) {
+        match c {
+            '[' => depth += 1,
+            ']' if depth == 1 => return (chars.get(i + 1) == Some(&'(')).then_some(i),
+            ']' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Greedily wrap styled text at word boundaries.
+fn wrap(text: &[(char, Style)], width: usize, first_indent: &str, indent: &str) -> Vec<String> {
+    let words = text
+        .split(|(c, style)| *c == ' ' && !style.code)
+        .filter(|w| !w.is_empty());
+
+    let mut lines = vec![];
+    let mut line = first_indent.to_string();
+    let mut line_len = first_indent.chars().count();
+    let mut line_empty = true;
+
+    for word in words {
+        if !line_empty && line_len + 1 + word.len() > width {
+            lines.push(line);
+            line = indent.to_string();
+            line_len = indent.chars().count();
+            line_empty = true;
+        }
+
+        if !line_empty {
+            line.push(' ');
+            line_len += 1;
+        }
+
+        line.push_str(&render_word(word));
+        line_len += word.len();
+        line_empty = false;
+    }
+
+    lines.push(line);
+    lines
+}
+
+fn render_word(word: &[(char, Style)]) -> String {
+    let mut s = String::new();
+    let mut current = Style::default();
+
+    for &(c, style) in word {
+        if style != current {
+            if current != Style::default() {
+                s.push_str(ANSI_RESET);
+            }
+            s.push_str(&style.ansi());
+            current = style;
+        }
+        s.push(c);
+    }
+
+    if current != Style::default() {
+        s.push_str(ANSI_RESET);
+    }
+
+    s
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, select_part};
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    #[test]
+    fn renders_headings_and_emphasis() {
+        let md = "\\--- Day 1: Not Quite Lisp ---\n----------\n\nTo *what floor* is it?";
+        let expected = format!(
+            "{ANSI_BOLD}--- Day 1: Not Quite Lisp ---{ANSI_RESET}\n\nTo {ANSI_BOLD}what{ANSI_RESET} {ANSI_BOLD}floor{ANSI_RESET} is it?"
+        );
+        assert_eq!(render(md, 80), expected);
+    }
+
+    #[test]
+    fn renders_code_and_links() {
+        let md = "A [16-bit](https://en.wikipedia.org/wiki/16-bit) signal `2*l*w` and \\*.";
+        let expected = format!("A 16-bit signal {ANSI_ITALIC}2*l*w{ANSI_RESET} and *.");
+        assert_eq!(render(md, 80), expected);
+    }
+
+    #[test]
+    fn frames_code_blocks() {
+        let md = "```\n456 -> y\nx AND y -> d\n\n```";
+        let expected = [
+            "  ┌──────────────┐",
+            "  │ 456 -> y     │",
+            "  │ x AND y -> d │",
+            "  └──────────────┘",
+        ]
+        .join("\n");
+        assert_eq!(render(md, 80), expected);
+    }
+
+    #[test]
+    fn wraps_paragraphs_and_lists() {
+        let md = "one two three four\n\n* five six seven";
+        let expected = [
+            "one two",
+            "three four",
+            "",
+            "  • five",
+            "    six",
+            "    seven",
+        ]
+        .join("\n");
+        assert_eq!(render(md, 10), expected);
+    }
+
+    #[test]
+    fn selects_parts() {
+        let md = "part one\n\\--- Part Two ---\npart two";
+        assert_eq!(select_part(md, 1), Some("part one\n"));
+        assert_eq!(select_part(md, 2), Some("\\--- Part Two ---\npart two"));
+        assert_eq!(select_part("part one", 2), None);
+    }
+}
@@ -5,11 +5,19 @@ pub mod commands;
 pub mod runner;
 
 pub use day::*;
+pub use report::Format;
 
+mod charts;
 mod day;
+mod editor;
+mod html;
+mod markdown;
+mod progress;
 mod readme_benchmarks;
+mod report;
 mod run_multi;
 mod timings;
+mod watch;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -39,6 +47,7 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Alternatively, `parse = func` names a parse function that is benchmarked on its own by `cargo time`.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
@@ -50,8 +59,11 @@ macro_rules! solution {
     ($day:expr, 2) => {
         $crate::solution!(@impl $day, [part_two, 2]);
     };
+    ($day:expr, parse = $parse:expr) => {
+        $crate::solution!(@impl $day, parse $parse, [part_one, 1] [part_two, 2]);
+    };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $day:expr, $( parse $parse:expr, )? $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
@@ -62,6 +74,7 @@ macro_rules! solution {
         fn main() {
             use $crate::template::runner::*;
             let input = $crate::template::read_file("inputs", DAY);
+            $( run_parse($parse, &input); )?
             $( run_part($func, &input, DAY, $part); )*
         }
     };
@@ -0,0 +1,311 @@
+/// Module that tracks stars and accepted answers per day.
+/// Replaces the legacy `#complete` / `#skip` markers appended to `data/puzzles/{day}.md`.
+use std::{
+    fs,
+    io::{self, BufWriter},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::{all_days, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+pub static PROGRESS_FILE_PATH: &str = "./data/progress.json";
+
+/// An accepted answer for a single part.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Star {
+    /// `None` for stars imported from a `#complete` marker without a recorded answer.
+    #[serde(default)]
+    pub answer: Option<String>,
+    /// Seconds since the unix epoch. `None` for stars imported from puzzle files.
+    #[serde(default)]
+    pub solved_at: Option<u64>,
+}
+
+/// Represents the progress of a single day.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DayProgress {
+    pub day: Day,
+    #[serde(default)]
+    pub part_1: Option<Star>,
+    #[serde(default)]
+    pub part_2: Option<Star>,
+    #[serde(default)]
+    pub skipped: bool,
+}
+
+/// Represents the progress of a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Progress {
+    pub data: Vec<DayProgress>,
+}
+
+impl DayProgress {
+    fn new(day: Day) -> Self {
+        Self {
+            day,
+            part_1: None,
+            part_2: None,
+            skipped: false,
+        }
+    }
+
+    pub fn stars(&self) -> u8 {
+        u8::from(self.part_1.is_some()) + u8::from(self.part_2.is_some())
+    }
+
+    /// The last day of advent only has a single puzzle, its second star is awarded once all others are collected.
+    pub fn is_complete(&self) -> bool {
+        self.part_1.is_some() && (self.part_2.is_some() || self.day == 25)
+    }
+}
+
+impl Progress {
+    /// Dehydrate progress to a JSON file.
+    pub fn store_file(&self) -> Result<(), io::Error> {
+        let file = BufWriter::new(fs::File::create(PROGRESS_FILE_PATH)?);
+        serde_json::to_writer_pretty(file, self).map_err(io::Error::from)
+    }
+
+    /// Rehydrate progress from a JSON file.
+    /// If not present, imports the legacy markers from `data/puzzles`.
+    pub fn read_from_file() -> Self {
+        match fs::read_to_string(PROGRESS_FILE_PATH) {
+            Ok(s) => serde_json::from_str(&s).unwrap_or_else(|e| {
+                eprintln!("Could not read \"{PROGRESS_FILE_PATH}\": {e}");
+                Progress::default()
+            }),
+            Err(_) => Progress::import_puzzle_files(),
+        }
+    }
+
+    /// Build progress from the answers and `#complete` / `#skip` markers in the downloaded puzzle descriptions.
+    pub fn import_puzzle_files() -> Self {
+        let data = all_days()
+            .filter_map(|day| {
+                let puzzle = fs::read_to_string(format!("data/puzzles/{day}.md")).ok()?;
+                Some(DayProgress::from_puzzle(day, &puzzle))
+            })
+            .collect();
+
+        Progress { data }
+    }
+
+    pub fn get(&self, day: Day) -> Option<&DayProgress> {
+        self.data.iter().find(|p| p.day == day)
+    }
+
+    fn get_or_insert(&mut self, day: Day) -> &mut DayProgress {
+        let idx = match self.data.iter().position(|p| p.day == day) {
+            Some(idx) => idx,
+            None => {
+                self.data.push(DayProgress::new(day));
+                self.data.sort_unstable_by_key(|p| p.day);
+                self.data.iter().position(|p| p.day == day).unwrap()
+            }
+        };
+        &mut self.data[idx]
+    }
+
+    /// Record an accepted answer for the given part, timestamped with the current time.
+    pub fn record_answer(&mut self, day: Day, part: u8, answer: &str) {
+        let star = Star {
+            answer: Some(answer.to_string()),
+            solved_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .ok()
+                .map(|d| d.as_secs()),
+        };
+
+        let entry = self.get_or_insert(day);
+        match part {
+            1 => entry.part_1 = Some(star),
+            _ => entry.part_2 = Some(star),
+        }
+    }
+
+    pub fn total_stars(&self) -> u8 {
+        self.data.iter().map(DayProgress::stars).sum()
+    }
+
+    pub fn is_day_complete(&self, day: Day) -> bool {
+        self.get(day).is_some_and(DayProgress::is_complete)
+    }
+
+    /// A day is done once it's complete or has been skipped.
+    pub fn is_day_done(&self, day: Day) -> bool {
+        self.get(day).is_some_and(|p| p.skipped || p.is_complete())
+    }
+
+    /// The earliest day that is neither complete nor skipped.
+    pub fn next_day(&self) -> Option<Day> {
+        all_days().find(|day| !self.is_day_done(*day))
+    }
+
+    /// Render progress as a 5x5 calendar.
+    pub fn render_calendar(&self) -> String {
+        let mut lines = vec![];
+
+        for week in all_days().collect::<Vec<_>>().chunks(5) {
+            let cells: Vec<String> = week
+                .iter()
+                .map(|&day| {
+                    let marker = match self.get(day) {
+                        Some(p) if p.is_complete() => format!("{ANSI_BOLD}**{ANSI_RESET}"),
+                        Some(p) if p.skipped => format!("{ANSI_ITALIC}--{ANSI_RESET}"),
+                        Some(p) if p.stars() == 1 => format!("{ANSI_BOLD}* {ANSI_RESET}"),
+                        _ => "  ".into(),
+                    };
+                    format!(" {day} {marker} ")
+                })
+                .collect();
+            lines.push(cells.join("|"));
+        }
+
+        lines.push(String::new());
+        lines.push(format!(
+            "{ANSI_BOLD}Stars:{ANSI_RESET} {ANSI_ITALIC}{}/50{ANSI_RESET}",
+            self.total_stars()
+        ));
+
+        lines.join("\n")
+    }
+}
+
+impl DayProgress {
+    fn from_puzzle(day: Day, puzzle: &str) -> Self {
+        let mut answers = puzzle.lines().filter_map(|line| {
+            line.strip_prefix("Your puzzle answer was `")?
+                .strip_suffix("`.")
+                .map(|answer| Star {
+                    answer: Some(answer.to_string()),
+                    solved_at: None,
+                })
+        });
+
+        let marker = puzzle.lines().last().map(str::trim);
+
+        // the puzzle is usually downloaded before the second part is submitted,
+        // so a `#complete` marker may be the only trace of the second star.
+        let unknown = || Star {
+            answer: None,
+            solved_at: None,
+        };
+        let is_complete = marker == Some("#complete");
+
+        let part_1 = answers.next().or_else(|| is_complete.then(unknown));
+        let part_2 = answers
+            .next()
+            .or_else(|| (is_complete && day != 25).then(unknown));
+
+        Self {
+            day,
+            part_1,
+            part_2,
+            skipped: marker == Some("#skip"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{DayProgress, Progress};
+
+    #[test]
+    fn imports_legacy_puzzle_markers() {
+        let puzzle = [
+            "\\--- Day 5: Doesn't He Have Intern-Elves For This? ---",
+            "Your puzzle answer was `258`.",
+            "\\--- Part Two ---",
+            "Your puzzle answer was `53`.",
+            "#skip",
+        ]
+        .join("\n");
+
+        let progress = DayProgress::from_puzzle(day!(5), &puzzle);
+        assert_eq!(progress.part_1.unwrap().answer.unwrap(), "258");
+        assert_eq!(progress.part_2.unwrap().answer.unwrap(), "53");
+        assert!(progress.skipped);
+    }
+
+    #[test]
+    fn imports_complete_marker_without_second_answer() {
+        let puzzle = [
+            "\\--- Day 1: Not Quite Lisp ---",
+            "Your puzzle answer was `280`.",
+            "\\--- Part Two ---",
+            "#complete",
+        ]
+        .join("\n");
+
+        let progress = DayProgress::from_puzzle(day!(1), &puzzle);
+        assert_eq!(progress.part_1.unwrap().answer.unwrap(), "280");
+        assert!(progress.part_2.unwrap().answer.is_none());
+        assert!(!progress.skipped);
+    }
+
+    #[test]
+    fn records_answers() {
+        let mut progress = Progress::default();
+        progress.record_answer(day!(3), 1, "42");
+        progress.record_answer(day!(1), 1, "1");
+        progress.record_answer(day!(1), 2, "2");
+
+        assert_eq!(progress.data[0].day, day!(1));
+        assert!(progress.is_day_complete(day!(1)));
+        assert!(!progress.is_day_complete(day!(3)));
+        assert!(progress.data[1]
+            .part_1
+            .as_ref()
+            .unwrap()
+            .solved_at
+            .is_some());
+        assert_eq!(progress.total_stars(), 3);
+    }
+
+    #[test]
+    fn completes_last_day_with_one_star() {
+        let mut progress = Progress::default();
+        progress.record_answer(day!(25), 1, "2650453");
+        assert!(progress.is_day_done(day!(25)));
+        assert_eq!(progress.total_stars(), 1);
+    }
+
+    #[test]
+    fn finds_next_day() {
+        let mut progress = Progress::default();
+        assert_eq!(progress.next_day(), Some(day!(1)));
+
+        progress.record_answer(day!(1), 1, "1");
+        progress.record_answer(day!(1), 2, "2");
+        progress.get_or_insert(day!(2)).skipped = true;
+        progress.record_answer(day!(3), 1, "3");
+        assert_eq!(progress.next_day(), Some(day!(3)));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut progress = Progress::default();
+        progress.record_answer(day!(2), 2, "abc");
+        progress.get_or_insert(day!(8)).skipped = true;
+
+        let json = serde_json::to_string(&progress).unwrap();
+        // fields are written in declaration order, so the file does not churn.
+        assert!(json.starts_with(r#"{"data":[{"day":2,"part_1":null,"part_2":{"answer":"abc""#));
+        let progress: Progress = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(progress.data.len(), 2);
+        assert!(progress.data[0].part_1.is_none());
+        assert_eq!(
+            progress.data[0].part_2.as_ref().unwrap().answer.as_deref(),
+            Some("abc")
+        );
+        assert!(progress.data[1].skipped);
+    }
+}
@@ -1,11 +1,19 @@
-/// Module that updates the readme me with timing information.
-/// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+/// Module that updates the readme with timing information and progress.
+/// The approach taken is similar to how `aoc-readme-stars` handles this: each generated block lives
+/// between two identical markers and is replaced as a whole.
+use std::{env, fs, io, path::Path, str::FromStr};
 
-use crate::template::timings::Timings;
+use crate::template::charts::Charts;
+use crate::template::markdown;
+use crate::template::progress::Progress;
+use crate::template::timings::{PartTiming, Timing, Timings};
 use crate::template::Day;
 
-static MARKER: &str = "<!--- benchmarking table --->";
+static BENCHMARKS_MARKER: &str = "<!--- benchmarking table --->";
+static RESULTS_MARKER: &str = "<!--- results table --->";
+
+/// Width of the share-of-total bar, in characters.
+const SHARE_BAR_WIDTH: usize = 10;
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -20,6 +28,177 @@ impl From<std::io::Error> for Error {
     }
 }
 
+/// An optional column of the benchmarks table.
+/// Configured as a comma-separated list in the `AOC_BENCHMARK_COLUMNS` environment variable.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Column {
+    Parse,
+    Part1,
+    Part2,
+    Total,
+    Samples,
+    StdDev,
+    Memory,
+    /// Bar showing the share of the day in the total run time.
+    Share,
+}
+
+static DEFAULT_COLUMNS: [Column; 2] = [Column::Part1, Column::Part2];
+
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "parse" => Ok(Column::Parse),
+            "part_1" => Ok(Column::Part1),
+            "part_2" => Ok(Column::Part2),
+            "total" => Ok(Column::Total),
+            "samples" => Ok(Column::Samples),
+            "std_dev" => Ok(Column::StdDev),
+            "memory" => Ok(Column::Memory),
+            "share" => Ok(Column::Share),
+            x => Err(format!("unknown benchmark column `{x}`")),
+        }
+    }
+}
+
+impl Column {
+    fn header(self) -> &'static str {
+        match self {
+            Column::Parse => "Parse",
+            Column::Part1 => "Part 1",
+            Column::Part2 => "Part 2",
+            Column::Total => "Total",
+            Column::Samples => "Samples",
+            Column::StdDev => "Std. dev.",
+            Column::Memory => "Memory",
+            Column::Share => "Share",
+        }
+    }
+
+    fn cell(self, timing: &Timing, total_nanos: f64) -> String {
+        let duration = |p: &Option<PartTiming>| p.as_ref().map_or("-".into(), |p| format!("`{p}`"));
+        let per_part = |f: fn(&PartTiming) -> String| {
+            let format = |p: &Option<PartTiming>| p.as_ref().map_or("-".into(), f);
+            format!("{} / {}", format(&timing.part_1), format(&timing.part_2))
+        };
+
+        match self {
+            Column::Parse => duration(&timing.parse),
+            Column::Part1 => duration(&timing.part_1),
+            Column::Part2 => duration(&timing.part_2),
+            Column::Total => format!("`{}`", PartTiming::new(timing.total_nanos())),
+            Column::Samples => per_part(|p| p.samples.to_string()),
+            Column::StdDev => per_part(|p| format!("`{}`", PartTiming::new(p.std_dev_nanos))),
+            Column::Memory => timing.peak_memory_bytes.map_or("-".into(), format_bytes),
+            Column::Share => share_bar(timing.total_nanos(), total_nanos),
+        }
+    }
+}
+
+/// Parse a comma-separated list of columns.
+pub fn parse_columns(s: &str) -> Result<Vec<Column>, String> {
+    s.split(',')
+        .filter(|c| !c.trim().is_empty())
+        .map(Column::from_str)
+        .collect()
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_bytes(bytes: u64) -> String {
+    let mib = bytes as f64 / (1024_f64 * 1024_f64);
+    format!("{mib:.1} MiB")
+}
+
+fn share_bar(nanos: f64, total_nanos: f64) -> String {
+    const EIGHTHS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+    let share = if total_nanos > 0_f64 {
+        (nanos / total_nanos).clamp(0_f64, 1_f64)
+    } else {
+        0_f64
+    };
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let eighths = (share * (SHARE_BAR_WIDTH * 8) as f64).round() as usize;
+
+    let mut bar = "█".repeat(eighths / 8);
+    match EIGHTHS[eighths % 8] {
+        ' ' => {}
+        partial => bar.push(partial),
+    }
+    let pad = SHARE_BAR_WIDTH - bar.chars().count();
+
+    format!("`{bar}{}` {:.1}%", " ".repeat(pad), share * 100_f64)
+}
+
+/// The data that generated blocks are rendered from.
+pub struct Sources {
+    pub timings: Timings,
+    pub progress: Progress,
+    pub columns: Vec<Column>,
+    pub year: Option<String>,
+    pub title: fn(Day) -> Option<String>,
+    pub charts: Charts,
+}
+
+impl Sources {
+    /// Read all sources from disk and the environment.
+    pub fn read() -> Result<Self, Error> {
+        let columns = match env::var("AOC_BENCHMARK_COLUMNS") {
+            Ok(columns) => parse_columns(&columns).map_err(Error::Parser)?,
+            Err(_) => DEFAULT_COLUMNS.to_vec(),
+        };
+
+        Ok(Sources {
+            timings: Timings::read_from_file(),
+            progress: Progress::read_from_file(),
+            columns,
+            year: env::var("AOC_YEAR").ok(),
+            title: markdown::puzzle_title,
+            charts: Charts::find(),
+        })
+    }
+}
+
+/// A block of the readme that is generated between two markers.
+#[derive(Clone, Copy, Debug)]
+enum Block {
+    Results,
+    Benchmarks,
+}
+
+static BLOCKS: [Block; 2] = [Block::Results, Block::Benchmarks];
+
+impl Block {
+    fn marker(self) -> &'static str {
+        match self {
+            Block::Results => RESULTS_MARKER,
+            Block::Benchmarks => BENCHMARKS_MARKER,
+        }
+    }
+
+    fn render(self, sources: &Sources) -> String {
+        let lines = match self {
+            Block::Results => construct_results(sources),
+            Block::Benchmarks => construct_table("##", sources),
+        };
+
+        [
+            vec![self.marker().to_string()],
+            lines,
+            vec![self.marker().to_string()],
+        ]
+        .concat()
+        .join("\n")
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
@@ -30,129 +209,257 @@ pub fn get_path_for_bin(day: Day) -> String {
     format!("./src/bin/{day}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Returns the position of a block, or `None` if the readme does not contain its markers.
+fn locate_table(readme: &str, marker: &str) -> Result<Option<TablePosition>, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
+
+    match matches.as_slice() {
+        [] => Ok(None),
+        [start, end] => Ok(Some(TablePosition {
+            pos_start: start.0,
+            pos_end: end.0 + end.1.len(),
+        })),
+        _ => Err(Error::Parser(format!(
+            "{marker}: expected exactly two occurences of marker in README."
+        ))),
+    }
+}
+
+fn construct_table(prefix: &str, sources: &Sources) -> Vec<String> {
+    let header = format!("{prefix} Benchmarks");
+    let total_nanos = sources.timings.total_millis() * 1_000_000_f64;
+    let columns = &sources.columns;
+
+    let mut lines: Vec<String> = vec![header, String::new()];
+
+    if let Some(path) = &sources.charts.overview {
+        lines.push(format!("![Benchmarks]({path})"));
+        lines.push(String::new());
+    }
+
+    lines.extend([
+        format!(
+            "| Day |{}",
+            columns
+                .iter()
+                .map(|c| format!(" {} |", c.header()))
+                .collect::<String>()
+        ),
+        format!("| :---: |{}", " :---: |".repeat(columns.len())),
+    ]);
 
-    if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
+    for timing in &sources.timings.data {
+        let path = get_path_for_bin(timing.day);
+        lines.push(format!(
+            "| [Day {}]({}) |{}",
+            timing.day.into_inner(),
+            path,
+            columns
+                .iter()
+                .map(|c| format!(" {} |", c.cell(timing, total_nanos)))
+                .collect::<String>()
         ));
     }
 
-    let pos_start = matches
-        .first()
-        .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+    lines.push(String::new());
+    lines.push(format!(
+        "**Total: {:.2}ms**",
+        sources.timings.total_millis()
+    ));
 
-    let pos_end = matches
-        .last()
-        .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+    if !sources.charts.history.is_empty() {
+        let links: Vec<String> = sources
+            .charts
+            .history
+            .iter()
+            .map(|(day, path)| format!("[Day {}]({path})", day.into_inner()))
+            .collect();
+        lines.push(String::new());
+        lines.push(format!("History: {}", links.join(" · ")));
+    }
 
-    Ok(TablePosition { pos_start, pos_end })
+    lines
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_results(sources: &Sources) -> Vec<String> {
+    let header = match &sources.year {
+        Some(year) => format!("## {year} Results"),
+        None => "## Results".into(),
+    };
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        "| Day | Puzzle | Part 1 | Part 2 | Solution |".into(),
+        "| :---: | :--- | :---: | :---: | :---: |".into(),
     ];
 
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+    let star = |solved: bool| if solved { "⭐" } else { " " };
+
+    for progress in sources.progress.data.iter().filter(|p| p.stars() > 0) {
+        let day = progress.day;
+        let url = sources.year.as_ref().map_or_else(
+            || "https://adventofcode.com".into(),
+            |year| format!("https://adventofcode.com/{year}/day/{}", day.into_inner()),
+        );
+        let path = get_path_for_bin(day);
+        let solution = if Path::new(&path).exists() {
+            format!("[{day}.rs]({path})")
+        } else {
+            "-".into()
+        };
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            "| [Day {}]({url}) | {} | {} | {} | {solution} |",
+            day.into_inner(),
+            (sources.title)(day).unwrap_or_else(|| "-".into()),
+            star(progress.part_1.is_some()),
+            star(progress.part_2.is_some()),
         ));
     }
 
     lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(format!("**Stars: {}/50**", sources.progress.total_stars()));
 
-    lines.join("\n")
+    lines
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
+/// Replace all blocks present in the readme. Fails if none are present.
+fn update_content(s: &mut String, sources: &Sources) -> Result<(), Error> {
+    let mut found = false;
+
+    for block in BLOCKS {
+        if let Some(positions) = locate_table(s, block.marker())? {
+            s.replace_range(
+                positions.pos_start..positions.pos_end,
+                &block.render(sources),
+            );
+            found = true;
+        }
+    }
+
+    if found {
+        Ok(())
+    } else {
+        Err(Error::Parser(
+            "Could not find any markers in README.".into(),
+        ))
+    }
 }
 
 pub fn update(timings: Timings) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    let sources = Sources {
+        timings,
+        ..Sources::read()?
+    };
+    update_content(&mut readme, &sources)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{
+        parse_columns, update_content, Column, Sources, BENCHMARKS_MARKER as MARKER, RESULTS_MARKER,
+    };
+    use crate::{
+        day,
+        template::{
+            charts::Charts,
+            progress::{DayProgress, Progress, Star},
+            timings::{PartTiming, Timing, Timings},
+        },
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    part_1: Some(PartTiming::new(10e6)),
+                    part_2: Some(PartTiming::new(20e6)),
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    part_1: Some(PartTiming::new(30e6)),
+                    part_2: Some(PartTiming::new(40e6)),
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
+                    part_1: Some(PartTiming::new(40e6)),
+                    part_2: Some(PartTiming::new(50e6)),
+                    ..Timing::new(day!(4))
                 },
             ],
         }
     }
 
+    fn get_mock_sources() -> Sources {
+        let star = || {
+            Some(Star {
+                answer: None,
+                solved_at: None,
+            })
+        };
+
+        Sources {
+            timings: get_mock_timings(),
+            progress: Progress {
+                data: vec![
+                    DayProgress {
+                        day: day!(1),
+                        part_1: star(),
+                        part_2: star(),
+                        skipped: false,
+                    },
+                    DayProgress {
+                        day: day!(3),
+                        part_1: star(),
+                        part_2: None,
+                        skipped: false,
+                    },
+                    DayProgress {
+                        day: day!(4),
+                        part_1: None,
+                        part_2: None,
+                        skipped: true,
+                    },
+                ],
+            },
+            columns: vec![Column::Part1, Column::Part2],
+            year: Some("2015".into()),
+            title: |day| (day == 1).then(|| "Not Quite Lisp".into()),
+            charts: Charts::default(),
+        }
+    }
+
     #[test]
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_sources()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_sources()).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_sources()).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_sources()).unwrap();
+        update_content(&mut s, &get_mock_sources()).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +467,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_sources()).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -168,10 +475,10 @@ mod tests {
             "## Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +487,63 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_optional_columns() {
+        let mut sources = get_mock_sources();
+        sources.columns = parse_columns("total,samples,memory,share").unwrap();
+        sources.timings.data[0].peak_memory_bytes = Some(3 * 1024 * 1024);
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, &sources).unwrap();
+
+        assert!(s.contains("| Day | Total | Samples | Memory | Share |"));
+        assert!(s.contains(
+            "| [Day 1](./src/bin/01.rs) | `30.0ms` | 1 / 1 | 3.0 MiB | `█▋        ` 15.8% |"
+        ));
+        assert!(
+            s.contains("| [Day 2](./src/bin/02.rs) | `70.0ms` | 1 / 1 | - | `███▋      ` 36.8% |")
+        );
+    }
+
+    #[test]
+    fn references_charts() {
+        let mut sources = get_mock_sources();
+        sources.charts = Charts {
+            overview: Some("./.assets/benchmarks/overview.svg".into()),
+            history: vec![(day!(2), "./.assets/benchmarks/02.svg".into())],
+        };
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, &sources).unwrap();
+
+        assert!(s.contains(
+            "## Benchmarks\n\n![Benchmarks](./.assets/benchmarks/overview.svg)\n\n| Day |"
+        ));
+        assert!(s.contains("\n\nHistory: [Day 2](./.assets/benchmarks/02.svg)\n"));
+    }
+
+    #[test]
+    fn rejects_unknown_columns() {
+        assert!(parse_columns("part_1,speed").is_err());
+    }
+
+    #[test]
+    fn format_results() {
+        let mut s = format!(
+            "{}\n{}\n{}{}",
+            RESULTS_MARKER, RESULTS_MARKER, MARKER, MARKER
+        );
+        update_content(&mut s, &get_mock_sources()).unwrap();
+
+        let results = s.split(MARKER).next().unwrap();
+        assert!(results.contains("## 2015 Results"));
+        assert!(results.contains(
+            "| [Day 1](https://adventofcode.com/2015/day/1) | Not Quite Lisp | ⭐ | ⭐ | [01.rs](./src/bin/01.rs) |"
+        ));
+        assert!(results.contains("| [Day 3](https://adventofcode.com/2015/day/3) | - | ⭐ |   |"));
+        assert!(!results.contains("Day 4"));
+        assert!(results.contains("**Stars: 3/50**"));
+        assert!(s.contains("## Benchmarks"));
+    }
 }
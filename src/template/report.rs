@@ -0,0 +1,232 @@
+/// Module that turns the output of solution runs into machine-readable records.
+use std::{fmt::Display, str::FromStr};
+
+use serde::Serialize;
+
+use crate::template::timings::{Timing, Timings};
+use crate::template::Day;
+
+/// Output format of multi-day commands.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Format {
+    /// Decorated output as printed by the solutions.
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "markdown" | "md" => Ok(Format::Markdown),
+            x => Err(format!(
+                "unknown format `{x}`, expecting one of text, json, csv or markdown"
+            )),
+        }
+    }
+}
+
+/// Outcome of running a single part.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    /// The part returned an answer.
+    Solved,
+    /// The part returned `None`.
+    Unsolved,
+    /// The day has not been scaffolded yet.
+    Missing,
+    /// The part did not report a result, e.g. because the solution panicked.
+    Failed,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Missing => "missing",
+            Status::Failed => "failed",
+        })
+    }
+}
+
+/// The result of running a single part of a day.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PartRecord {
+    pub day: Day,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    /// Duration as printed by the runner, e.g. `74.13ns`.
+    pub duration: Option<String>,
+    pub nanos: Option<f64>,
+    pub samples: Option<u64>,
+}
+
+impl PartRecord {
+    pub fn new(day: Day, part: u8, status: Status) -> Self {
+        PartRecord {
+            day,
+            part,
+            status,
+            answer: None,
+            duration: None,
+            nanos: None,
+            samples: None,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Render records in a machine-readable format. Timings are included in JSON output if present.
+pub fn render(format: Format, records: &[PartRecord], timings: Option<&Timings>) -> String {
+    match format {
+        Format::Text => String::new(),
+        Format::Json => render_json(records, timings),
+        Format::Csv => render_csv(records),
+        Format::Markdown => render_markdown(records),
+    }
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    parts: &'a [PartRecord],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timings: Option<&'a [Timing]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_nanos: Option<f64>,
+}
+
+fn render_json(records: &[PartRecord], timings: Option<&Timings>) -> String {
+    let report = JsonReport {
+        parts: records,
+        timings: timings.map(|t| t.data.as_slice()),
+        total_nanos: timings.map(|t| t.total_millis() * 1_000_000_f64),
+    };
+
+    serde_json::to_string_pretty(&report).unwrap_or_default()
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn render_csv(records: &[PartRecord]) -> String {
+    let mut lines = vec!["day,part,status,answer,duration,nanos,samples".to_string()];
+
+    for r in records {
+        lines.push(format!(
+            "{},{},{},{},{},{},{}",
+            r.day,
+            r.part,
+            r.status,
+            escape_csv(r.answer.as_deref().unwrap_or_default()),
+            r.duration.as_deref().unwrap_or_default(),
+            r.nanos.map(|x| x.to_string()).unwrap_or_default(),
+            r.samples.map(|x| x.to_string()).unwrap_or_default(),
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn render_markdown(records: &[PartRecord]) -> String {
+    let mut lines = vec![
+        "| Day | Part | Status | Answer | Duration | Samples |".to_string(),
+        "| :---: | :---: | :---: | :---: | :---: | :---: |".to_string(),
+    ];
+
+    for r in records {
+        let answer = r
+            .answer
+            .as_deref()
+            .map_or_else(|| "-".into(), |x| format!("`{}`", x.replace('\n', "<br>")));
+
+        lines.push(format!(
+            "| {} | {} | {} | {} | {} | {} |",
+            r.day,
+            r.part,
+            r.status,
+            answer,
+            r.duration
+                .as_deref()
+                .map_or("-".into(), |x| format!("`{x}`")),
+            r.samples.map_or("-".into(), |x| x.to_string()),
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, Format, PartRecord, Status};
+    use crate::day;
+
+    fn get_mock_records() -> Vec<PartRecord> {
+        vec![
+            PartRecord {
+                answer: Some("1,2".into()),
+                duration: Some("74.13ns".into()),
+                nanos: Some(74.13),
+                samples: Some(10000),
+                ..PartRecord::new(day!(1), 1, Status::Solved)
+            },
+            PartRecord::new(day!(1), 2, Status::Unsolved),
+        ]
+    }
+
+    #[test]
+    fn renders_csv() {
+        let csv = render(Format::Csv, &get_mock_records(), None);
+        let expected = [
+            "day,part,status,answer,duration,nanos,samples",
+            "01,1,solved,\"1,2\",74.13ns,74.13,10000",
+            "01,2,unsolved,,,,",
+        ]
+        .join("\n");
+        assert_eq!(csv, expected);
+    }
+
+    #[test]
+    fn renders_markdown() {
+        let md = render(Format::Markdown, &get_mock_records(), None);
+        assert!(md.contains("| 01 | 1 | solved | `1,2` | `74.13ns` | 10000 |"));
+        assert!(md.contains("| 01 | 2 | unsolved | - | - | - |"));
+    }
+
+    #[test]
+    fn renders_json() {
+        let json = render(Format::Json, &get_mock_records(), None);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let parts = value["parts"].as_array().unwrap();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0]["day"], 1);
+        assert_eq!(parts[0]["status"], "solved");
+        assert_eq!(parts[0]["answer"], "1,2");
+        assert!(parts[1]["answer"].is_null());
+        assert!(value.get("timings").is_none());
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("json".parse::<Format>().unwrap(), Format::Json);
+        assert_eq!("md".parse::<Format>().unwrap(), Format::Markdown);
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
@@ -1,49 +1,99 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, io, path::Path};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, DaySet, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
-    timings::{Timing, Timings},
+    progress::Progress,
+    report::{PartRecord, Status},
+    timings::{RunMetadata, Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Resolve a day set against the stored progress and timings.
+pub fn resolve_day_set(days: &DaySet, timings: &Timings) -> HashSet<Day> {
+    let progress = Progress::read_from_file();
+
+    days.resolve(
+        |day| progress.is_day_complete(day),
+        |day| {
+            timings
+                .data
+                .iter()
+                .find(|t| t.day == day)
+                .map(Timing::total_nanos)
+        },
+    )
+}
+
+/// The results of running multiple days.
+pub struct MultiRun {
+    /// Timings of the days that were run, if they were benchmarked.
+    pub timings: Option<Timings>,
+    pub parts: Vec<PartRecord>,
+}
+
+/// Run the solutions of the given days in order.
+/// With `is_quiet`, the output of the solutions is not echoed and only collected into records.
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    is_quiet: bool,
+) -> MultiRun {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut parts: Vec<PartRecord> = Vec::with_capacity(days_to_run.len() * 2);
 
+    let metadata = is_timed.then(|| RunMetadata::current(is_release));
     let mut need_space = false;
 
     // NOTE: use non-duplicate, sorted day values.
     all_days()
         .filter(|day| days_to_run.contains(day))
         .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+            if !is_quiet {
+                if need_space {
+                    println!();
+                }
+                need_space = true;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+                println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+                println!("------");
+            }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let output = child_commands::run_solution(day, is_timed, is_release, is_quiet).unwrap();
 
             if output.is_empty() {
-                println!("Not solved.");
+                if !is_quiet {
+                    println!("Not solved.");
+                }
+                let status = if Path::new(&get_path_for_bin(day)).exists() {
+                    Status::Failed
+                } else {
+                    Status::Missing
+                };
+                parts.extend([1, 2].map(|part| PartRecord::new(day, part, status)));
             } else {
+                parts.extend(child_commands::parse_parts(&output, day));
                 let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+                timings.push(Timing {
+                    metadata: metadata.clone(),
+                    ..val
+                });
             }
         });
 
-    if is_timed {
-        let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
-        Some(timings)
-    } else {
-        None
+    let timings = is_timed.then_some(Timings { data: timings });
+
+    if let Some(timings) = &timings {
+        if !is_quiet {
+            let total_millis = timings.total_millis();
+            println!(
+                "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
     }
+
+    MultiRun { timings, parts }
 }
 
 #[allow(dead_code)]
@@ -68,6 +118,8 @@ pub fn get_path_for_bin(day: Day) -> String {
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
     use super::{get_path_for_bin, Error};
+    use crate::template::report::{PartRecord, Status};
+    use crate::template::timings::{parse_duration, BenchStats, Timing, BENCH_STATS_PREFIX};
     use crate::template::Day;
     use std::{
         io::{BufRead, BufReader},
@@ -77,7 +129,12 @@ pub mod child_commands {
     };
 
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        is_quiet: bool,
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
             return Ok(vec![]);
@@ -118,7 +175,9 @@ pub mod child_commands {
 
         for line in stdout.lines() {
             let line = line.unwrap();
-            println!("{line}");
+            if !is_quiet && !line.starts_with(BENCH_STATS_PREFIX) {
+                println!("{line}");
+            }
             output.push(line);
         }
 
@@ -128,65 +187,114 @@ pub mod child_commands {
         Ok(output)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    /// Collect the benchmark statistics a solution bin prints when run with `--time`.
+    pub fn parse_exec_time(output: &[String], day: Day) -> Timing {
+        let mut timing = Timing::new(day);
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        for stats in parse_bench_stats(output) {
+            timing.peak_memory_bytes = timing.peak_memory_bytes.max(stats.peak_memory_bytes);
+
+            match stats.part {
+                0 => timing.parse = Some(stats.timing),
+                1 => timing.part_1 = Some(stats.timing),
+                2 => timing.part_2 = Some(stats.timing),
+                _ => {}
+            }
+        }
+
+        timing
+    }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
+    fn parse_bench_stats(output: &[String]) -> impl Iterator<Item = BenchStats> + '_ {
+        output.iter().filter_map(|l| {
+            let json = l.strip_prefix(BENCH_STATS_PREFIX)?;
+            serde_json::from_str(json)
+                .map_err(|e| eprintln!("Could not parse timings from line: {l} ({e})"))
+                .ok()
+        })
+    }
+
+    /// Parse the answers printed by a solution bin into one record per part.
+    pub fn parse_parts(output: &[String], day: Day) -> Vec<PartRecord> {
+        // timed runs overwrite the line with `\r`, only the final text is relevant.
+        let lines: Vec<String> = output
+            .iter()
+            .filter(|l| !l.starts_with(BENCH_STATS_PREFIX))
+            .map(|l| strip_ansi(l.rsplit('\r').next().unwrap_or_default()))
+            .collect();
+        let stats: Vec<BenchStats> = parse_bench_stats(output).collect();
+
+        [1, 2]
+            .map(|part| {
+                let prefix = format!("Part {part}: ");
+                let Some(pos) = lines.iter().position(|l| l.starts_with(&prefix)) else {
+                    return PartRecord::new(day, part, Status::Failed);
                 };
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                let line = lines[pos][prefix.len()..].trim_end();
+                if line.starts_with('✖') {
+                    return PartRecord::new(day, part, Status::Unsolved);
                 }
 
-                timings.total_nanos += nanos;
-            });
+                let (answer, timing) =
+                    match line.strip_suffix(')').and_then(|l| l.rsplit_once(" (")) {
+                        Some((answer, timing)) => (answer.trim(), Some(timing)),
+                        None => (line, None),
+                    };
+
+                // multiline answers are printed below the `Part N:` line.
+                let answer = if answer == "▼" {
+                    lines[pos + 1..]
+                        .iter()
+                        .take_while(|l| !l.starts_with("Part "))
+                        .map(String::as_str)
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                        .trim_end()
+                        .to_string()
+                } else {
+                    answer.to_string()
+                };
 
-        timings
-    }
+                let (duration, samples) = match timing.map(|t| t.split_once(" @ ")) {
+                    Some(Some((duration, samples))) => (
+                        Some(duration),
+                        samples.trim_end_matches(" samples").parse().ok(),
+                    ),
+                    Some(None) => (timing, Some(1)),
+                    None => (None, None),
+                };
+
+                // prefer the exact statistics of benchmarked runs over the printed duration.
+                let (nanos, samples) = match stats.iter().find(|s| s.part == part) {
+                    Some(s) => (Some(s.timing.mean_nanos), Some(s.timing.samples)),
+                    None => (duration.and_then(parse_duration), samples),
+                };
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+                PartRecord {
+                    answer: Some(answer),
+                    duration: duration.map(String::from),
+                    nanos,
+                    samples,
+                    ..PartRecord::new(day, part, Status::Solved)
+                }
+            })
+            .into_iter()
+            .collect()
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    fn strip_ansi(line: &str) -> String {
+        let mut out = String::with_capacity(line.len());
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                // skip until the final byte of the escape sequence.
+                chars.by_ref().find(char::is_ascii_alphabetic);
+            } else {
+                out.push(c);
+            }
+        }
+        out
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,7 +313,8 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_exec_time, parse_parts};
+        use crate::template::report::Status;
 
         use crate::day;
 
@@ -213,19 +322,22 @@ pub mod child_commands {
         fn parses_execution_times() {
             let res = parse_exec_time(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
+                    "Part 1: 0 (74.1ns @ 100000 samples)".into(),
+                    r##"#bench {"part":1,"mean_nanos":74.13,"min_nanos":70.0,"max_nanos":90.0,"std_dev_nanos":2.5,"samples":100000}"##.into(),
+                    "Part 2: 10 (74.1ms @ 99999 samples)".into(),
+                    r##"#bench {"part":2,"mean_nanos":74130000.0,"min_nanos":74000000.0,"max_nanos":75000000.0,"std_dev_nanos":1000.0,"samples":99999}"##.into(),
                     "".into(),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos(), 74130074.13_f64);
+            assert_eq!(res.part_1.as_ref().unwrap().samples, 100000);
+            assert_approx_eq!(res.part_1.unwrap().std_dev_nanos, 2.5_f64);
+            assert_eq!(res.part_2.unwrap().to_string(), "74.1ms");
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
+        fn ignores_printed_durations() {
             let res = parse_exec_time(
                 &[
                     "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
@@ -234,9 +346,8 @@ pub mod child_commands {
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.total_nanos(), 0_f64);
+            assert_eq!(res.part_1.is_none(), true);
         }
 
         #[test]
@@ -249,9 +360,45 @@ pub mod child_commands {
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
+            assert_approx_eq!(res.total_nanos(), 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn parses_parts() {
+            let res = parse_parts(
+                &[
+                    "Part 1: \x1b[1m42\x1b[0m > benching\rPart 1: \x1b[1m42\x1b[0m (1.5ms @ 200 samples)".into(),
+                    r##"#bench {"part":1,"mean_nanos":1512345.0,"min_nanos":1.0,"max_nanos":2.0,"std_dev_nanos":0.0,"samples":200}"##.into(),
+                    "Part 2: ✖             ".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res[0].status, Status::Solved);
+            assert_eq!(res[0].answer.as_deref(), Some("42"));
+            assert_eq!(res[0].duration.as_deref(), Some("1.5ms"));
+            assert_approx_eq!(res[0].nanos.unwrap(), 1_512_345_f64);
+            assert_eq!(res[0].samples, Some(200));
+            assert_eq!(res[1].status, Status::Unsolved);
+            assert_eq!(res[1].answer, None);
+        }
+
+        #[test]
+        fn parses_multiline_parts() {
+            let res = parse_parts(
+                &[
+                    "Part 1: ▼  (3.0µs)".into(),
+                    "##".into(),
+                    "#.".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res[0].answer.as_deref(), Some("##\n#."));
+            assert_eq!(res[0].samples, Some(1));
+            assert_eq!(res[1].status, Status::Failed);
+        }
     }
 }
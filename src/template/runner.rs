@@ -4,21 +4,49 @@ use std::hint::black_box;
 use std::io::{stdout, Write};
 use std::process::Output;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, fs, process};
 
+use crate::template::progress::Progress;
+use crate::template::readme_benchmarks;
+use crate::template::timings::{BenchStats, PartTiming, Timings, BENCH_STATS_PREFIX};
 use crate::template::ANSI_BOLD;
 use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
 
 pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
+    let (result, duration, stats) =
         run_timed(func, input, |result| print_result(result, &part_str, ""));
 
+    let samples = stats.as_ref().map_or(1, |s| s.samples);
     print_result(&result, &part_str, &format_duration(&duration, samples));
 
+    if let (Some(_), Some(stats)) = (&result, stats) {
+        print_stats(part, stats);
+    }
+
     if let Some(result) = result {
-        submit_result(result, day, part);
+        if let Some(Ok(output)) = submit_result(&result, day, part) {
+            if aoc_cli::is_right_answer(&output) {
+                record_answer(&result, day, part);
+            }
+        }
+    }
+}
+
+/// Store an accepted answer in the progress file.
+fn record_answer<T: Display>(result: &T, day: Day, part: u8) {
+    let mut progress = Progress::read_from_file();
+    progress.record_answer(day, part, &result.to_string());
+    match progress.store_file() {
+        Ok(()) => println!("Recorded star for day {day}, part {part}."),
+        Err(e) => eprintln!("Failed to store progress: {e}"),
+    }
+
+    // readmes without generated blocks are left alone, and so are their benchmarks if the
+    // timings can't be read.
+    if let Ok(timings) = Timings::load() {
+        let _ = readme_benchmarks::update(timings);
     }
 }
 
@@ -29,7 +57,7 @@ fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, Option<PartTiming>) {
     let timer = Instant::now();
     let result = {
         #[cfg(feature = "dhat-heap")]
@@ -41,16 +69,19 @@ fn run_timed<I: Copy, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    if std::env::args().any(|x| x == "--time") {
+        let stats = bench(func, input, &base_time);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let mean = stats
+            .as_ref()
+            .map_or(base_time, |s| Duration::from_nanos(s.mean_nanos as u64));
+        (result, mean, stats)
     } else {
-        (base_time, 1)
-    };
-
-    (result, run.0, run.1)
+        (result, base_time, None)
+    }
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> Option<PartTiming> {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
@@ -67,22 +98,43 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    PartTiming::from_samples(&timers)
+}
+
+/// Benchmark the parse function of a solution. Its result is discarded, the parts parse on their own.
+pub fn run_parse<I: Copy, T>(func: impl Fn(I) -> T, input: I) {
+    let (_, duration, stats) = run_timed(func, input, |_| print!("Parse:"));
+
+    let samples = stats.as_ref().map_or(1, |s| s.samples);
+    print!("\r");
+    println!("Parse:{}", format_duration(&duration, samples));
+
+    if let Some(stats) = stats {
+        print_stats(0, stats);
+    }
+}
+
+/// Peak resident set size of the current process. Only available on Linux.
+fn peak_memory_bytes() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|l| l.starts_with("VmHWM:"))?;
+    let kilobytes: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kilobytes * 1024)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+/// Print benchmark statistics on a line that `cargo time` picks up and hides from its output.
+fn print_stats(part: u8, timing: PartTiming) {
+    let stats = BenchStats {
+        part,
+        timing,
+        peak_memory_bytes: peak_memory_bytes(),
+    };
+    if let Ok(json) = serde_json::to_string(&stats) {
+        println!("{BENCH_STATS_PREFIX}{json}");
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
+fn format_duration(duration: &Duration, samples: u64) -> String {
     if samples == 1 {
         format!(" ({duration:.1?})")
     } else {
@@ -129,7 +181,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 ///  1. we are in `--release` mode.
 ///  2. aoc-cli is installed.
 fn submit_result<T: Display>(
-    result: T,
+    result: &T,
     day: Day,
     part: u8,
 ) -> Option<Result<Output, aoc_cli::AocCommandError>> {
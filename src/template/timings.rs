@@ -1,17 +1,167 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
-use tinyjson::JsonValue;
+use std::{
+    env, fmt, fs,
+    io::{self, BufWriter},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
 
 use crate::template::Day;
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+static HISTORY_FILE_PATH: &str = "./data/timings_history.json";
+
+/// Version of the schema written by [`Timings::store_file`].
+/// Files without a version are read as the legacy `{ "data": [...] }` format.
+pub const TIMINGS_VERSION: u32 = 2;
+
+/// Prefix of the line a solution prints its benchmark statistics on when run with `--time`.
+pub static BENCH_STATS_PREFIX: &str = "#bench ";
+
+/// Benchmark statistics of a single part, in nanoseconds.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PartTiming {
+    pub mean_nanos: f64,
+    pub min_nanos: f64,
+    pub max_nanos: f64,
+    pub std_dev_nanos: f64,
+    /// Number of samples the statistics are based on, `0` for entries migrated from the legacy format.
+    pub samples: u64,
+}
+
+impl PartTiming {
+    /// Create statistics of a single sample.
+    pub fn new(nanos: f64) -> Self {
+        PartTiming {
+            mean_nanos: nanos,
+            min_nanos: nanos,
+            max_nanos: nanos,
+            std_dev_nanos: 0_f64,
+            samples: 1,
+        }
+    }
+
+    /// Compute statistics over a set of measured durations.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let n = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / n;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+
+        Some(PartTiming {
+            mean_nanos: mean,
+            min_nanos: nanos.iter().copied().fold(f64::INFINITY, f64::min),
+            max_nanos: nanos.iter().copied().fold(0_f64, f64::max),
+            std_dev_nanos: variance.sqrt(),
+            samples: nanos.len() as u64,
+        })
+    }
+}
+
+/// Displays the mean duration, e.g. `74.1ns`.
+impl fmt::Display for PartTiming {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_nanos(self.mean_nanos))
+    }
+}
+
+/// Format a duration in nanoseconds with a fitting unit, e.g. `1.2ms`.
+pub fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos as u64);
+    format!("{duration:.1?}")
+}
+
+/// Benchmark statistics of a part as printed by a solution bin.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BenchStats {
+    /// `0` for the optional parse function of a solution.
+    pub part: u8,
+    #[serde(flatten)]
+    pub timing: PartTiming,
+    /// Peak resident memory of the solution process after benching this part, if it can be determined.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_memory_bytes: Option<u64>,
+}
+
+/// Describes the environment a benchmark was recorded in.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RunMetadata {
+    /// Unix timestamp in seconds.
+    pub recorded_at: u64,
+    pub release: bool,
+    /// Output of `rustc --version`, if available.
+    pub rustc: Option<String>,
+    pub os: String,
+    pub arch: String,
+}
+
+impl RunMetadata {
+    /// Describe a run that is started now.
+    pub fn current(release: bool) -> Self {
+        let rustc = Command::new(env::var("RUSTC").unwrap_or_else(|_| "rustc".into()))
+            .arg("--version")
+            .output()
+            .ok()
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|version| version.trim().to_string());
+
+        RunMetadata {
+            recorded_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            release,
+            rustc,
+            os: env::consts::OS.into(),
+            arch: env::consts::ARCH.into(),
+        }
+    }
+}
 
 /// Represents benchmark times for a single day.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
-    pub total_nanos: f64,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
+    /// Timing of the parse function, for solutions that declare one.
+    #[serde(default)]
+    pub parse: Option<PartTiming>,
+    #[serde(default)]
+    pub peak_memory_bytes: Option<u64>,
+    /// Not present for entries migrated from the legacy format.
+    pub metadata: Option<RunMetadata>,
+}
+
+impl Timing {
+    pub fn new(day: Day) -> Self {
+        Timing {
+            day,
+            part_1: None,
+            part_2: None,
+            parse: None,
+            peak_memory_bytes: None,
+            metadata: None,
+        }
+    }
+
+    /// Sum of the mean durations of both parts.
+    pub fn total_nanos(&self) -> f64 {
+        [&self.part_1, &self.part_2]
+            .into_iter()
+            .flatten()
+            .map(|p| p.mean_nanos)
+            .sum()
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -21,20 +171,84 @@ pub struct Timings {
     pub data: Vec<Timing>,
 }
 
+/// On-disk representation of [`Timings`].
+#[derive(Serialize, Deserialize)]
+struct TimingsFile {
+    version: u32,
+    days: Vec<Timing>,
+}
+
 impl Timings {
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
-        let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
-        json.format_to(&mut file)
+    pub fn store_file(&self) -> Result<(), io::Error> {
+        let file = BufWriter::new(fs::File::create(TIMINGS_FILE_PATH)?);
+        serde_json::to_writer_pretty(file, &TimingsFile::from(self)).map_err(io::Error::from)
+    }
+
+    /// Read timings from a JSON file. If not present, returns empty timings.
+    /// Files in the legacy format are migrated transparently and rewritten on the next store.
+    fn load_file(path: &str) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => Timings::from_json(&s).map_err(|e| format!("Could not read \"{path}\": {e}")),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Timings::default()),
+            Err(e) => Err(format!("Could not read \"{path}\": {e}")),
+        }
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
+    /// Fails if the file is malformed, so that storing does not overwrite it.
+    pub fn load() -> Result<Self, String> {
+        Self::load_file(TIMINGS_FILE_PATH)
+    }
+
+    /// Like [`Timings::load`], but reports an unreadable file and returns empty timings.
+    /// Only for commands that display timings without storing them.
     pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
+        Self::load().unwrap_or_else(|e| {
+            eprintln!("{e}");
+            Timings::default()
+        })
+    }
+
+    /// Append benchmarked days to the history file, which keeps every stored run instead of the latest one per day.
+    pub fn append_history(&self) -> Result<(), io::Error> {
+        let mut history =
+            Self::load_history().map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        history
+            .data
+            .extend(self.data.iter().filter(|t| t.metadata.is_some()).cloned());
+
+        let file = BufWriter::new(fs::File::create(HISTORY_FILE_PATH)?);
+        serde_json::to_writer_pretty(file, &TimingsFile::from(&history)).map_err(io::Error::from)
+    }
+
+    /// Read the benchmark history. If not present, returns empty timings.
+    pub fn load_history() -> Result<Self, String> {
+        Self::load_file(HISTORY_FILE_PATH)
+    }
+
+    /// Like [`Timings::load_history`], but reports an unreadable file and returns empty timings.
+    pub fn read_history() -> Self {
+        Self::load_history().unwrap_or_else(|e| {
+            eprintln!("{e}");
+            Timings::default()
+        })
+    }
+
+    /// Parse timings from JSON, in any supported version.
+    pub fn from_json(s: &str) -> Result<Self, String> {
+        let json: serde_json::Value = serde_json::from_str(s).map_err(|e| e.to_string())?;
+
+        match json.get("version").map(serde_json::Value::as_u64) {
+            None => legacy::migrate(json),
+            Some(Some(version)) if version <= u64::from(TIMINGS_VERSION) => {
+                let file: TimingsFile = serde_json::from_value(json).map_err(|e| e.to_string())?;
+                Ok(Timings { data: file.days })
+            }
+            Some(_) => Err(format!(
+                "unsupported timings version, expected at most {TIMINGS_VERSION}."
+            )),
+        }
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
@@ -51,13 +265,13 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|t| t.day);
         Timings { data }
     }
 
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        self.data.iter().map(Timing::total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
     pub fn is_day_complete(&self, day: Day) -> bool {
@@ -67,111 +281,79 @@ impl Timings {
     }
 }
 
-/* -------------------------------------------------------------------------- */
-
-impl From<Timings> for JsonValue {
-    fn from(value: Timings) -> Self {
-        let mut map: HashMap<String, JsonValue> = HashMap::new();
-
-        map.insert(
-            "data".into(),
-            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
-        );
-
-        JsonValue::Object(map)
-    }
-}
-
-impl TryFrom<String> for Timings {
-    type Error = String;
-
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
-
-        let json_data = json
-            .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
-            .get("data")
-            .ok_or("expected JSON document to have key `data`.")?
-            .get::<Vec<JsonValue>>()
-            .ok_or("expected `json.data` to be an array.")?;
-
-        Ok(Timings {
-            data: json_data
-                .iter()
-                .map(Timing::try_from)
-                .collect::<Result<_, _>>()?,
-        })
+impl From<&Timings> for TimingsFile {
+    fn from(value: &Timings) -> Self {
+        TimingsFile {
+            version: TIMINGS_VERSION,
+            days: value.data.clone(),
+        }
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-impl From<&Timing> for JsonValue {
-    fn from(value: &Timing) -> Self {
-        let mut map: HashMap<String, JsonValue> = HashMap::new();
-
-        map.insert("day".into(), JsonValue::String(value.day.to_string()));
-        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
-
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
-
-        map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
-
-        map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
-
-        JsonValue::Object(map)
+/// Parse a duration formatted with `{:.1?}`, e.g. `74.13ns`, to nanoseconds.
+pub fn parse_duration(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    let parse = |postfix: &str| s.strip_suffix(postfix)?.parse::<f64>().ok();
+
+    if s.ends_with("ns") {
+        parse("ns")
+    } else if s.ends_with("µs") {
+        parse("µs").map(|x| x * 1000_f64)
+    } else if s.ends_with("ms") {
+        parse("ms").map(|x| x * 1_000_000_f64)
+    } else {
+        parse("s").map(|x| x * 1_000_000_000_f64)
     }
 }
 
-impl TryFrom<&JsonValue> for Timing {
-    type Error = String;
-
-    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
-        let json = value
-            .get::<HashMap<String, JsonValue>>()
-            .ok_or("Expected timing to be a JSON object.")?;
+/// The format used before timings were versioned: `{ "data": [...] }` with durations as display strings.
+mod legacy {
+    use serde::Deserialize;
 
-        let day = json
-            .get("day")
-            .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
-            .ok_or("Expected timing.day to be a Day struct.")?;
+    use super::{parse_duration, PartTiming, Timing, Timings};
+    use crate::template::Day;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
-
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+    #[derive(Deserialize)]
+    struct LegacyTimings {
+        data: Vec<LegacyTiming>,
+    }
 
-        let total_nanos = json
-            .get("total_nanos")
-            .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
+    #[derive(Deserialize)]
+    struct LegacyTiming {
+        day: String,
+        part_1: Option<String>,
+        part_2: Option<String>,
+    }
 
-        Ok(Timing {
-            day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
+    fn migrate_part(part: Option<&str>) -> Result<Option<PartTiming>, String> {
+        part.map(|s| {
+            let nanos = parse_duration(s).ok_or(format!("invalid legacy duration `{s}`."))?;
+            Ok(PartTiming {
+                samples: 0,
+                ..PartTiming::new(nanos)
+            })
         })
+        .transpose()
+    }
+
+    pub fn migrate(json: serde_json::Value) -> Result<Timings, String> {
+        let legacy: LegacyTimings = serde_json::from_value(json).map_err(|e| e.to_string())?;
+
+        let data = legacy
+            .data
+            .into_iter()
+            .map(|t| {
+                Ok(Timing {
+                    part_1: migrate_part(t.part_1.as_deref())?,
+                    part_2: migrate_part(t.part_2.as_deref())?,
+                    ..Timing::new(t.day.parse::<Day>().map_err(|e| e.to_string())?)
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Timings { data })
     }
 }
 
@@ -181,28 +363,24 @@ impl TryFrom<&JsonValue> for Timing {
 mod tests {
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{PartTiming, Timing, Timings};
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    part_1: Some(PartTiming::new(1e+10)),
+                    part_2: Some(PartTiming::new(2e+10)),
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    part_1: Some(PartTiming::new(3e+10)),
+                    part_2: Some(PartTiming::new(4e+10)),
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: None,
-                    total_nanos: 4e+10,
+                    part_1: Some(PartTiming::new(4e+10)),
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -213,107 +391,142 @@ mod tests {
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
-            let timings = Timings::try_from(json).unwrap();
+            let json = r#"{ "version": 2, "days": [{ "day": 1, "part_1": { "mean_nanos": 1000000, "min_nanos": 900000, "max_nanos": 1100000, "std_dev_nanos": 50000, "samples": 100 }, "part_2": null, "metadata": { "recorded_at": 1700000000, "release": true, "rustc": null, "os": "linux", "arch": "x86_64" } }] }"#;
+            let timings = Timings::from_json(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(timing.part_1.as_ref().unwrap().samples, 100);
+            assert_eq!(timing.part_1.as_ref().unwrap().to_string(), "1.0ms");
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.total_nanos(), 1_000_000_f64);
+            assert!(timing.metadata.as_ref().unwrap().release);
+        }
+
+        #[test]
+        fn migrates_legacy_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1.5ms", "part_2": null, "total_nanos": 1500000 }] }"#;
+            let timings = Timings::from_json(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.day, day!(1));
+            assert_eq!(timing.part_1.as_ref().unwrap().mean_nanos, 1_500_000_f64);
+            assert_eq!(timing.part_1.as_ref().unwrap().samples, 0);
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.metadata, None);
         }
 
         #[test]
         fn handles_empty_timings() {
-            let json = r#"{ "data": [] }"#.to_string();
-            let timings = Timings::try_from(json).unwrap();
+            let timings = Timings::from_json(r#"{ "version": 2, "days": [] }"#).unwrap();
             assert_eq!(timings.data.len(), 0);
+            let timings = Timings::from_json(r#"{ "data": [] }"#).unwrap();
+            assert_eq!(timings.data.len(), 0);
+        }
+
+        #[test]
+        fn rejects_future_versions() {
+            assert!(Timings::from_json(r#"{ "version": 99, "days": [] }"#).is_err());
         }
 
         #[test]
         #[should_panic]
         fn panics_for_invalid_json() {
-            let json = r#"{}"#.to_string();
-            Timings::try_from(json).unwrap();
+            Timings::from_json("{}").unwrap();
         }
 
         #[test]
         #[should_panic]
         fn panics_for_malformed_timings() {
-            let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
-            Timings::try_from(json).unwrap();
+            let json = r#"{ "version": 2, "days": [{ "day": 1 }, { "day": 26 }] }"#;
+            Timings::from_json(json).unwrap();
+        }
+
+        #[test]
+        fn reports_unreadable_files() {
+            let dir = std::env::temp_dir().join(format!("aoc-timings-{}", std::process::id()));
+            std::fs::create_dir_all(&dir).unwrap();
+            let path = dir.join("timings.json");
+            let path = path.to_str().unwrap();
+
+            assert!(Timings::load_file(path).unwrap().data.is_empty());
+            std::fs::write(path, r#"{ "version": 99, "days": [] }"#).unwrap();
+            assert!(Timings::load_file(path).unwrap_err().contains(path));
+
+            std::fs::remove_dir_all(&dir).unwrap();
         }
     }
 
     mod serialization {
         use super::get_mock_timings;
-        use std::collections::HashMap;
-        use tinyjson::JsonValue;
+        use crate::template::timings::{Timings, TimingsFile, TIMINGS_VERSION};
 
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
-            let value = JsonValue::try_from(timings).unwrap();
-            assert_eq!(
-                value
-                    .get::<HashMap<String, JsonValue>>()
-                    .unwrap()
-                    .get("data")
-                    .unwrap()
-                    .get::<Vec<JsonValue>>()
-                    .unwrap()
-                    .len(),
-                3
-            );
+            let json = serde_json::to_string(&TimingsFile::from(&timings)).unwrap();
+            let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+            assert_eq!(value["version"], TIMINGS_VERSION);
+            assert_eq!(value["days"].as_array().unwrap().len(), 3);
+            assert_eq!(value["days"][0]["day"], 1);
+            assert_eq!(Timings::from_json(&json).unwrap().data, timings.data);
+        }
+    }
+
+    mod statistics {
+        use crate::template::timings::PartTiming;
+        use std::time::Duration;
+
+        #[test]
+        fn computes_sample_statistics() {
+            let samples = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_nanos);
+            let stats = PartTiming::from_samples(&samples).unwrap();
+            assert_eq!(stats.mean_nanos, 5_f64);
+            assert_eq!(stats.min_nanos, 2_f64);
+            assert_eq!(stats.max_nanos, 9_f64);
+            assert_eq!(stats.std_dev_nanos, 2_f64);
+            assert_eq!(stats.samples, 8);
+            assert_eq!(PartTiming::from_samples(&[]), None);
         }
     }
 
     mod is_day_complete {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::timings::{PartTiming, Timing, Timings},
         };
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
-                    total_nanos: 3_000_000_000_f64,
+                    part_1: Some(PartTiming::new(1e+6)),
+                    part_2: Some(PartTiming::new(2e+6)),
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: None,
-                    total_nanos: 1_000_000_000_f64,
+                    part_1: Some(PartTiming::new(1e+6)),
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
-                }],
+                data: vec![Timing::new(day!(1))],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -329,12 +542,7 @@ mod tests {
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(3))],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -349,19 +557,14 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(2))],
             };
             let merged = timings.merge(&other);
 
             assert_eq!(merged.data.len(), 3);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[1].total_nanos(), 0_f64);
             assert_eq!(merged.data[2].day, day!(4));
         }
 
@@ -0,0 +1,125 @@
+/// Module that re-runs a command whenever the files of a day change.
+/// Polls modification times instead of relying on platform file events.
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{Day, ANSI_ITALIC, ANSI_RESET};
+
+const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// Time without further changes before a burst of saves triggers a run.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// Add every file below `dir` to `paths`, skipping the other days in `src/bin`.
+fn add_sources(dir: &Path, paths: &mut Vec<PathBuf>) {
+    for path in fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+    {
+        if path == Path::new("src/bin") {
+            continue;
+        } else if path.is_dir() {
+            add_sources(&path, paths);
+        } else {
+            paths.push(path);
+        }
+    }
+}
+
+/// Returns the files that affect the result of a day: its module, the library and its data files.
+fn watched_paths(day: Day) -> Vec<PathBuf> {
+    let mut paths = vec![
+        PathBuf::from(format!("src/bin/{day}.rs")),
+        PathBuf::from(format!("data/inputs/{day}.txt")),
+        PathBuf::from(format!("data/examples/{day}.txt")),
+    ];
+    // new library files are picked up too, since the paths are collected on every poll.
+    add_sources(Path::new("src"), &mut paths);
+
+    // pick up numbered examples such as `01-2.txt`, including newly created ones.
+    let prefix = format!("{day}-");
+    paths.extend(
+        fs::read_dir("data/examples")
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&prefix))
+            }),
+    );
+
+    paths
+}
+
+fn snapshot(day: Day) -> Snapshot {
+    watched_paths(day)
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Block until the watched files change and then stay unchanged for [`DEBOUNCE`].
+fn wait_for_change(day: Day, previous: &Snapshot) -> Snapshot {
+    let mut current = previous.clone();
+
+    while &current == previous {
+        thread::sleep(POLL_INTERVAL);
+        current = snapshot(day);
+    }
+
+    loop {
+        thread::sleep(DEBOUNCE);
+        let next = snapshot(day);
+        if next == current {
+            return current;
+        }
+        current = next;
+    }
+}
+
+/// Run `f` once, then again after every change to the files of `day`. Never returns.
+pub fn watch(day: Day, mut f: impl FnMut()) -> ! {
+    let mut state = snapshot(day);
+
+    loop {
+        print!("{ANSI_CLEAR}");
+        f();
+        println!();
+        println!("{ANSI_ITALIC}Watching day {day} for changes, press Ctrl-C to exit.{ANSI_RESET}");
+
+        state = wait_for_change(day, &state);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::watched_paths;
+    use crate::day;
+
+    #[test]
+    fn watches_the_library_but_not_other_days() {
+        let paths = watched_paths(day!(1));
+        assert!(paths.contains(&PathBuf::from("src/bin/01.rs")));
+        assert!(paths.contains(&PathBuf::from("src/lib.rs")));
+        assert!(paths.contains(&PathBuf::from("src/template/watch.rs")));
+        assert!(!paths.contains(&PathBuf::from("src/bin/02.rs")));
+    }
+}